@@ -0,0 +1,133 @@
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/**
+ * Configures the HTTP client shared by every service of an `Api`.
+ */
+pub struct ApiBuilder {
+    base_url: String,
+    client: Option<reqwest::Client>,
+    client_builder: reqwest::ClientBuilder,
+    retry: Option<std::sync::Arc<dyn crate::Retry>>,
+}
+
+impl ApiBuilder {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            client: None,
+            client_builder: reqwest::Client::builder().user_agent(USER_AGENT),
+            retry: None,
+        }
+    }
+
+    /**
+     * Uses an already configured client, the other client settings of this
+     * builder are ignored.
+     */
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /**
+     * Sets the total timeout of each request.
+     */
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client_builder = self.client_builder.timeout(timeout);
+        self
+    }
+
+    /**
+     * Sets the timeout of the connect phase.
+     */
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client_builder = self.client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /**
+     * Overrides the default `peertube/<version>` user agent.
+     */
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.client_builder = self.client_builder.user_agent(user_agent);
+        self
+    }
+
+    /**
+     * Adds a proxy.
+     */
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.client_builder = self.client_builder.proxy(proxy);
+        self
+    }
+
+    /**
+     * Trusts an additional root certificate, for instances using a private
+     * certificate authority.
+     */
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.client_builder = self.client_builder.tls_certs_merge([certificate]);
+        self
+    }
+
+    /**
+     * Sets headers sent with every request.
+     */
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.client_builder = self.client_builder.default_headers(headers);
+        self
+    }
+
+    /**
+     * Sets the policy used to retry failed requests, by default requests
+     * aren't retried.
+     */
+    pub fn retry<R: crate::Retry + 'static>(mut self, retry: R) -> Self {
+        self.retry = Some(std::sync::Arc::new(retry));
+        self
+    }
+
+    pub fn build(self) -> crate::Result<crate::Api> {
+        let client = match self.client {
+            Some(client) => client,
+            None => self.client_builder.build()?,
+        };
+
+        let config = crate::Config {
+            base_url: self.base_url,
+            client,
+            retry: self.retry,
+            session: Default::default(),
+        };
+
+        Ok(crate::Api::from_config(config))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn build() {
+        let instance = crate::test::instance().await;
+        let api = crate::ApiBuilder::new(&instance.url)
+            .timeout(std::time::Duration::from_secs(30))
+            .user_agent("peertube-test")
+            .retry(crate::ExponentialBackoff::default().max_retries(2))
+            .build();
+
+        assert!(api.is_ok());
+    }
+
+    #[tokio::test]
+    async fn client() {
+        let instance = crate::test::instance().await;
+        let api = crate::Api::builder(&instance.url)
+            .client(reqwest::Client::new())
+            .build()
+            .unwrap();
+
+        let config = api.config.get().await;
+
+        assert!(config.is_ok());
+    }
+}
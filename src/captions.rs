@@ -0,0 +1,407 @@
+/*!
+ * SubRip (`.srt`) and WebVTT (`.vtt`) caption files.
+ *
+ * Only the timing and the text of the cues are kept, WebVTT cue identifiers,
+ * cue settings and `NOTE`, `STYLE` or `REGION` blocks are dropped.
+ *
+ * ```
+ * use peertube::captions::{self, Format};
+ *
+ * let srt = "1\n00:00:01,000 --> 00:00:04,000\nHello\n";
+ * let cues = captions::parse(srt)?;
+ *
+ * assert!(captions::validate(&cues).is_empty());
+ * assert_eq!(
+ *     captions::format(&cues, Format::WebVtt),
+ *     "WEBVTT\n\n00:00:01.000 --> 00:00:04.000\nHello\n",
+ * );
+ * # Ok::<_, peertube::captions::ParseError>(())
+ * ```
+ */
+
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cue {
+    pub start: Duration,
+    pub end: Duration,
+    /**
+     * Text of the cue, lines are separated by `\n`.
+     */
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Srt,
+    WebVtt,
+}
+
+impl Format {
+    /**
+     * Guesses the format from the extension of a file name.
+     */
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+
+        if extension.eq_ignore_ascii_case("srt") {
+            Some(Self::Srt)
+        } else if extension.eq_ignore_ascii_case("vtt") {
+            Some(Self::WebVtt)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Detects the format from the content, WebVTT files start with a
+     * `WEBVTT` line.
+     */
+    pub fn detect(text: &str) -> Self {
+        if text.trim_start_matches('\u{feff}').starts_with("WEBVTT") {
+            Self::WebVtt
+        } else {
+            Self::Srt
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::WebVtt => "vtt",
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid caption at line {line}: {message}")]
+pub struct ParseError {
+    /**
+     * Line of the error, starting at 1.
+     */
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: &str) -> Self {
+        Self {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+/**
+ * Problem found by `validate`, `cue` is the index of the faulty cue.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Issue {
+    /**
+     * The cue ends before it starts, or has no duration.
+     */
+    InvalidTiming { cue: usize },
+    /**
+     * The cue has no text.
+     */
+    Empty { cue: usize },
+    /**
+     * The cue starts before the previous one.
+     */
+    Unordered { cue: usize },
+    /**
+     * The cue starts before the end of the previous one.
+     */
+    Overlap { cue: usize },
+}
+
+/**
+ * Parses a caption file, the format is detected from the content.
+ */
+pub fn parse(text: &str) -> Result<Vec<Cue>, ParseError> {
+    parse_as(text, Format::detect(text))
+}
+
+/**
+ * Parses a caption file in the given format.
+ *
+ * Timestamps are accepted with both `,` and `.` as millisecond separator,
+ * and without hours.
+ */
+pub fn parse_as(text: &str, format: Format) -> Result<Vec<Cue>, ParseError> {
+    let text = text.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut blocks = blocks(&text);
+    let mut cues = Vec::new();
+
+    if format == Format::WebVtt {
+        match blocks.next() {
+            Some((_, lines)) if lines[0].starts_with("WEBVTT") => (),
+            _ => return Err(ParseError::new(1, "missing WEBVTT header")),
+        }
+    }
+
+    for (line, lines) in blocks {
+        if format == Format::WebVtt
+            && ["NOTE", "STYLE", "REGION"]
+                .iter()
+                .any(|x| lines[0].starts_with(x))
+        {
+            continue;
+        }
+
+        // The timing line can be preceded by a cue number or identifier
+        let Some(timing) = lines.iter().take(2).position(|x| x.contains("-->")) else {
+            return Err(ParseError::new(line, "missing cue timing"));
+        };
+        let line = line + timing;
+
+        let (start, end) = lines[timing]
+            .split_once("-->")
+            .ok_or_else(|| ParseError::new(line, "missing cue timing"))?;
+        // WebVTT cue settings follow the end timestamp
+        let end = end.split_whitespace().next().unwrap_or_default();
+
+        cues.push(Cue {
+            start: parse_timestamp(start.trim())
+                .ok_or_else(|| ParseError::new(line, "invalid start timestamp"))?,
+            end: parse_timestamp(end)
+                .ok_or_else(|| ParseError::new(line, "invalid end timestamp"))?,
+            text: lines[timing + 1..].join("\n"),
+        });
+    }
+
+    Ok(cues)
+}
+
+/**
+ * Non-empty blocks of lines separated by blank lines, with the number of
+ * their first line.
+ */
+fn blocks(text: &str) -> impl Iterator<Item = (usize, Vec<&str>)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+
+    for (n, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| (n + 1, Vec::new()))
+                .1
+                .push(line);
+        }
+    }
+
+    blocks.extend(current);
+    blocks.into_iter()
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (time, millis) = timestamp.split_once([',', '.'])?;
+
+    if millis.len() != 3 {
+        return None;
+    }
+
+    let parts = time
+        .split(':')
+        .map(|x| x.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let (hours, minutes, seconds) = match parts[..] {
+        [minutes, seconds] => (0, minutes, seconds),
+        [hours, minutes, seconds] => (hours, minutes, seconds),
+        _ => return None,
+    };
+
+    if minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+
+    let seconds = hours
+        .checked_mul(3600)?
+        .checked_add(minutes * 60 + seconds)?;
+
+    Duration::from_secs(seconds).checked_add(Duration::from_millis(millis.parse().ok()?))
+}
+
+fn format_timestamp(timestamp: Duration, separator: char) -> String {
+    let seconds = timestamp.as_secs();
+
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        timestamp.subsec_millis()
+    )
+}
+
+/**
+ * Serializes cues in the given format.
+ */
+pub fn format(cues: &[Cue], format: Format) -> String {
+    let mut output = match format {
+        Format::Srt => String::new(),
+        Format::WebVtt => "WEBVTT\n".to_string(),
+    };
+
+    for (n, cue) in cues.iter().enumerate() {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+
+        let separator = match format {
+            Format::Srt => {
+                output.push_str(&format!("{}\n", n + 1));
+                ','
+            }
+            Format::WebVtt => '.',
+        };
+
+        output.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(cue.start, separator),
+            format_timestamp(cue.end, separator)
+        ));
+
+        if !cue.text.is_empty() {
+            output.push_str(&cue.text);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/**
+ * Converts a caption file to another format.
+ */
+pub fn convert(text: &str, to: Format) -> Result<String, ParseError> {
+    Ok(format(&parse(text)?, to))
+}
+
+/**
+ * Checks the timing and the content of cues, an empty list means the cues
+ * are valid.
+ */
+pub fn validate(cues: &[Cue]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for (n, cue) in cues.iter().enumerate() {
+        if cue.end <= cue.start {
+            issues.push(Issue::InvalidTiming { cue: n });
+        }
+
+        if cue.text.trim().is_empty() {
+            issues.push(Issue::Empty { cue: n });
+        }
+
+        if let Some(previous) = n.checked_sub(1).map(|x| &cues[x]) {
+            if cue.start < previous.start {
+                issues.push(Issue::Unordered { cue: n });
+            } else if cue.start < previous.end {
+                issues.push(Issue::Overlap { cue: n });
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    fn cue(start: u64, end: u64, text: &str) -> super::Cue {
+        super::Cue {
+            start: Duration::from_millis(start),
+            end: Duration::from_millis(end),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_srt() {
+        let srt = std::fs::read_to_string("fixtures/caption.srt").unwrap();
+
+        let cues = super::parse(&srt).unwrap();
+
+        assert_eq!(
+            cues,
+            [cue(
+                4_845_138,
+                4_848_164,
+                "You'd say anything now\nto get what you want."
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_vtt() {
+        let vtt = "WEBVTT - Test\r\n\r\nNOTE a comment\r\n\r\nintro\r\n00:01.000 --> 00:02.500 align:start\r\nHello\r\n\r\n\r\n01:00:00.000 --> 01:00:01.000\r\n<v Bob>Bye\r\n";
+
+        let cues = super::parse(vtt).unwrap();
+
+        assert_eq!(
+            cues,
+            [
+                cue(1_000, 2_500, "Hello"),
+                cue(3_600_000, 3_601_000, "<v Bob>Bye"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = super::parse("1\n00:00:01,000 --> 00:00:61,000\nHello\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let err = super::parse_as("1\nHello\n", super::Format::WebVtt).unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = super::parse("1\n00:00:01,000 --> 00:00:02,000\nHello\n\nHello\n").unwrap_err();
+        assert_eq!(err.line, 5);
+
+        let err =
+            super::parse("1\n9999999999999999999:00:00,000 --> 00:00:02,000\nHello\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn convert() {
+        let srt = std::fs::read_to_string("fixtures/caption.srt").unwrap();
+
+        let vtt = super::convert(&srt, super::Format::WebVtt).unwrap();
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n01:20:45.138 --> 01:20:48.164\nYou'd say anything now\nto get what you want.\n"
+        );
+
+        let srt = super::convert(&vtt, super::Format::Srt).unwrap();
+        assert_eq!(
+            srt,
+            "1\n01:20:45,138 --> 01:20:48,164\nYou'd say anything now\nto get what you want.\n"
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let cues = [
+            cue(1_000, 2_000, "one"),
+            cue(1_500, 3_000, "two"),
+            cue(1_000, 1_000, ""),
+            cue(5_000, 6_000, "four"),
+        ];
+
+        assert_eq!(
+            super::validate(&cues),
+            [
+                super::Issue::Overlap { cue: 1 },
+                super::Issue::InvalidTiming { cue: 2 },
+                super::Issue::Empty { cue: 2 },
+                super::Issue::Unordered { cue: 2 },
+            ]
+        );
+    }
+}
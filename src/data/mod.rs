@@ -90,6 +90,113 @@ pub struct Video {
     pub wait_transcoding: Option<bool>,
 }
 
+/**
+ * Video with its files, as returned by `Videos::get`.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoDetails {
+    #[serde(flatten)]
+    pub video: Video,
+    pub support: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub comments_enabled: Option<bool>,
+    pub download_enabled: bool,
+    #[serde(default)]
+    pub tracker_urls: Vec<String>,
+    /**
+     * Web video files, empty if the instance only generates HLS playlists.
+     */
+    #[serde(default)]
+    pub files: Vec<VideoFile>,
+    #[serde(default)]
+    pub streaming_playlists: Vec<StreamingPlaylist>,
+}
+
+impl VideoDetails {
+    /**
+     * Master playlist URL of the HLS stream.
+     */
+    pub fn hls_playlist(&self) -> Option<&str> {
+        self.streaming_playlists
+            .iter()
+            .find(|x| matches!(x.r#type, StreamingPlaylistType::Hls))
+            .map(|x| x.playlist_url.as_str())
+    }
+
+    /**
+     * Web video files followed by the HLS files.
+     */
+    pub fn all_files(&self) -> impl Iterator<Item = &VideoFile> {
+        self.files
+            .iter()
+            .chain(self.streaming_playlists.iter().flat_map(|x| &x.files))
+    }
+
+    /**
+     * File with the highest resolution, preferring web video files.
+     */
+    pub fn best_file(&self) -> Option<&VideoFile> {
+        self.all_files()
+            .min_by_key(|x| std::cmp::Reverse(x.resolution.id))
+    }
+}
+
+impl std::ops::Deref for VideoDetails {
+    type Target = Video;
+
+    fn deref(&self) -> &Self::Target {
+        &self.video
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoFile {
+    pub id: Option<u32>,
+    pub resolution: Resolution,
+    pub size: u64,
+    pub fps: f32,
+    pub magnet_uri: Option<String>,
+    pub torrent_url: Option<String>,
+    pub torrent_download_url: Option<String>,
+    pub file_url: String,
+    pub file_download_url: String,
+    pub metadata_url: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Resolution {
+    pub id: u32,
+    pub label: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingPlaylist {
+    pub id: u32,
+    pub r#type: StreamingPlaylistType,
+    pub playlist_url: String,
+    pub segments_sha256_url: String,
+    #[serde(default)]
+    pub files: Vec<VideoFile>,
+    #[serde(default)]
+    pub redundancies: Vec<StreamingPlaylistRedundancy>,
+}
+
+#[derive(Debug, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum StreamingPlaylistType {
+    Hls = 1,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingPlaylistRedundancy {
+    pub base_url: String,
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct Category {
     pub id: Option<u32>,
@@ -126,9 +233,10 @@ pub struct Privacy {
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ScheduledUpdate {
-    update_at: String,
-    privacy: u32,
+    pub update_at: String,
+    pub privacy: u32,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -137,18 +245,77 @@ pub struct State {
     pub label: String,
 }
 
+impl State {
+    /**
+     * Known state, `None` for states added by newer PeerTube versions.
+     */
+    pub fn kind(&self) -> Option<VideoState> {
+        VideoState::from_id(self.id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoState {
+    Published = 1,
+    ToTranscode = 2,
+    ToImport = 3,
+    WaitingForLive = 4,
+    LiveEnded = 5,
+    ToMoveToExternalStorage = 6,
+    TranscodingFailed = 7,
+    ToMoveToExternalStorageFailed = 8,
+    ToEdit = 9,
+    ToMoveToFileSystem = 10,
+    ToMoveToFileSystemFailed = 11,
+}
+
+impl VideoState {
+    pub fn from_id(id: u32) -> Option<Self> {
+        let state = match id {
+            1 => Self::Published,
+            2 => Self::ToTranscode,
+            3 => Self::ToImport,
+            4 => Self::WaitingForLive,
+            5 => Self::LiveEnded,
+            6 => Self::ToMoveToExternalStorage,
+            7 => Self::TranscodingFailed,
+            8 => Self::ToMoveToExternalStorageFailed,
+            9 => Self::ToEdit,
+            10 => Self::ToMoveToFileSystem,
+            11 => Self::ToMoveToFileSystemFailed,
+            _ => return None,
+        };
+
+        Some(state)
+    }
+
+    /**
+     * The video won't leave this state by itself: the live ended or a
+     * processing step failed.
+     */
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::LiveEnded
+                | Self::TranscodingFailed
+                | Self::ToMoveToExternalStorageFailed
+                | Self::ToMoveToFileSystemFailed
+        )
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct UserHistory {
     current_time: u32,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 pub(crate) struct OauthClient {
     pub client_id: String,
     pub client_secret: String,
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct Token {
     pub access_token: String,
     pub token_type: String,
@@ -159,6 +326,7 @@ pub struct Token {
 #[derive(Debug, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Data {
+    #[serde(rename = "user")]
     NewUser(NewUser),
 }
 
@@ -202,6 +370,69 @@ pub struct User {
     pub video_channels: Vec<ChannelSummary>,
 }
 
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Registration {
+    pub id: u32,
+    pub state: RegistrationStateInfo,
+    pub registration_reason: String,
+    pub moderation_response: Option<String>,
+    pub username: String,
+    pub email: String,
+    pub email_verified: Option<bool>,
+    pub account_display_name: Option<String>,
+    pub channel_handle: Option<String>,
+    pub channel_display_name: Option<String>,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+    /**
+     * User created when the registration was accepted.
+     */
+    pub user: Option<UserId>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RegistrationStateInfo {
+    pub id: RegistrationState,
+    pub label: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum RegistrationState {
+    Pending = 1,
+    Rejected = 2,
+    Accepted = 3,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UserId {
+    pub id: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OtpRequest {
+    pub otp_request: TwoFactorRequest,
+}
+
+/**
+ * Pending two-factor activation, see `Users::confirm_two_factor`.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorRequest {
+    pub request_token: String,
+    /**
+     * Base32 secret, to give to an authenticator or `totp::code`.
+     */
+    pub secret: String,
+    /**
+     * `otpauth://` URI, usually shown as a QR code.
+     */
+    pub uri: String,
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct Role {
     pub id: RoleId,
@@ -385,6 +616,15 @@ pub enum NewContent {
     PlaylistElement(NewPlaylistElement),
 }
 
+impl NewContent {
+    /**
+     * Error for a response creating another kind of content than `expected`.
+     */
+    pub(crate) fn unexpected(&self, expected: &str) -> crate::Error {
+        crate::Error::UnexpectedResponse(format!("expected a new {expected}, got {self:?}"))
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct NewAbuse {
     pub id: u32,
@@ -401,6 +641,164 @@ pub struct NewVideo {
     pub uuid: String,
 }
 
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Chapter {
+    /**
+     * Start of the chapter, in seconds.
+     */
+    pub timecode: u32,
+    pub title: String,
+}
+
+impl Chapter {
+    /**
+     * Extracts chapters from a description using the `00:00 Title`
+     * convention, one chapter per line.
+     *
+     * Timecodes can be `MM:SS` or `H:MM:SS`, optionally between brackets and
+     * followed by a separator like `-`. Other lines are ignored.
+     */
+    pub fn parse(description: &str) -> Vec<Self> {
+        description.lines().filter_map(Self::parse_line).collect()
+    }
+
+    fn parse_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (timecode, title) = line.split_once(char::is_whitespace)?;
+        let timecode = timecode
+            .trim_start_matches(['(', '['])
+            .trim_end_matches([')', ']']);
+        let title = title
+            .trim_start()
+            .trim_start_matches(['-', '–', '—', ':', '|'])
+            .trim();
+
+        if title.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            timecode: parse_timecode(timecode)?,
+            title: title.to_string(),
+        })
+    }
+
+    /**
+     * Formats chapters as a description block, the reverse of
+     * `Chapter::parse`.
+     */
+    pub fn format(chapters: &[Self]) -> String {
+        chapters
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl std::fmt::Display for Chapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hours = self.timecode / 3600;
+        let minutes = self.timecode / 60 % 60;
+        let seconds = self.timecode % 60;
+
+        if hours > 0 {
+            write!(f, "{hours}:{minutes:02}:{seconds:02} {}", self.title)
+        } else {
+            write!(f, "{minutes:02}:{seconds:02} {}", self.title)
+        }
+    }
+}
+
+fn parse_timecode(timecode: &str) -> Option<u32> {
+    let parts = timecode
+        .split(':')
+        .map(|x| {
+            (!x.is_empty() && x.len() <= 2 && x.bytes().all(|x| x.is_ascii_digit()))
+                .then(|| x.parse::<u32>().ok())
+                .flatten()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let (hours, minutes, seconds) = match parts[..] {
+        [minutes, seconds] => (0, minutes, seconds),
+        [hours, minutes, seconds] if minutes < 60 => (hours, minutes, seconds),
+        _ => return None,
+    };
+
+    (seconds < 60).then_some(hours * 3600 + minutes * 60 + seconds)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct Chapters {
+    pub chapters: Vec<Chapter>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoPassword {
+    pub id: u32,
+    pub password: String,
+    pub video_id: u32,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+/**
+ * Original file of a video.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSource {
+    pub filename: Option<String>,
+    pub input_filename: Option<String>,
+    /**
+     * Only given if the original file is kept by the instance.
+     */
+    pub file_download_url: Option<String>,
+    pub resolution: Option<Resolution>,
+    pub size: Option<u64>,
+    pub fps: Option<f32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub created_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+/**
+ * State of a resumable upload, can be saved to resume the upload from another
+ * process.
+ */
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadSession {
+    pub endpoint: String,
+    pub upload_id: String,
+    pub file: std::path::PathBuf,
+    pub size: u64,
+    pub content_type: String,
+    pub chunk_size: usize,
+}
+
+impl UploadSession {
+    pub const DEFAULT_CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> crate::Result<Self> {
+        let contents = std::fs::read(path)?;
+
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> crate::Result<()> {
+        std::fs::write(path, serde_json::to_vec(self)?)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn path(&self) -> String {
+        format!("{}?upload_id={}", self.endpoint, self.upload_id)
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct NewImport {
     pub id: u32,
@@ -426,9 +824,109 @@ pub struct NewPlaylistElement {
 #[serde(rename_all = "camelCase")]
 pub struct Live {
     pub rtmp_url: String,
+    /**
+     * Only given if RTMPS is enabled on the instance.
+     */
+    pub rtmps_url: Option<String>,
     pub stream_key: String,
     pub save_replay: bool,
     pub permanent_live: bool,
+    pub latency_mode: Option<LatencyMode>,
+    pub replay_settings: Option<ReplaySettings>,
+}
+
+impl Live {
+    /**
+     * Full RTMP URL to push the stream to, with the stream key.
+     */
+    pub fn ingest_url(&self) -> String {
+        ingest_url(&self.rtmp_url, &self.stream_key)
+    }
+
+    /**
+     * Full RTMPS URL to push the stream to, if RTMPS is enabled.
+     */
+    pub fn secure_ingest_url(&self) -> Option<String> {
+        self.rtmps_url
+            .as_deref()
+            .map(|url| ingest_url(url, &self.stream_key))
+    }
+}
+
+fn ingest_url(url: &str, stream_key: &str) -> String {
+    format!("{}/{stream_key}", url.trim_end_matches('/'))
+}
+
+/**
+ * Live with the video it is broadcast on, as returned by
+ * `Live::create_or_get`.
+ */
+#[derive(Debug)]
+pub struct LiveStream {
+    /**
+     * UUID of the video.
+     */
+    pub video_id: String,
+    pub live: Live,
+}
+
+impl std::ops::Deref for LiveStream {
+    type Target = Live;
+
+    fn deref(&self) -> &Self::Target {
+        &self.live
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, serde_repr::Deserialize_repr, serde_repr::Serialize_repr,
+)]
+#[repr(u8)]
+pub enum LatencyMode {
+    Default = 1,
+    High = 2,
+    Small = 3,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReplaySettings {
+    /**
+     * Privacy of the replay video.
+     */
+    pub privacy: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveSession {
+    pub id: u32,
+    pub start_date: chrono::DateTime<chrono::offset::Utc>,
+    pub end_date: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub error: Option<LiveSessionError>,
+    pub save_replay: bool,
+    pub ending_processed: bool,
+    pub replay_video: Option<ReplayVideo>,
+}
+
+#[derive(Debug, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum LiveSessionError {
+    BadSocketHealth = 1,
+    DurationExceeded = 2,
+    QuotaExceeded = 3,
+    FfmpegError = 4,
+    Blacklisted = 5,
+    RunnerJobError = 6,
+    RunnerJobCancel = 7,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayVideo {
+    pub id: u32,
+    pub uuid: String,
+    #[serde(rename = "shortUUID")]
+    pub short_uuid: String,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -436,6 +934,11 @@ pub struct Live {
 pub struct Caption {
     pub caption_path: String,
     pub language: Language,
+    /**
+     * Whether the caption was generated by the instance, only given by
+     * instances supporting transcription.
+     */
+    pub automatically_generated: Option<bool>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -655,11 +1158,23 @@ pub struct Job {
     pub id: u32,
     pub state: JobState,
     pub r#type: JobType,
-    pub data: (),
-    pub error: Option<()>,
+    /**
+     * Job specific data.
+     */
+    pub data: serde_json::Value,
+    pub error: Option<String>,
     pub created_at: chrono::DateTime<chrono::offset::Utc>,
-    pub finished_on: chrono::DateTime<chrono::offset::Utc>,
-    pub processed_on: chrono::DateTime<chrono::offset::Utc>,
+    pub finished_on: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub processed_on: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+
+impl Job {
+    /**
+     * UUID of the video the job works on, if any.
+     */
+    pub fn video_uuid(&self) -> Option<&str> {
+        self.data.get("videoUUID")?.as_str()
+    }
 }
 
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
@@ -689,7 +1204,7 @@ impl std::fmt::Display for JobState {
     }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum JobType {
     ActivitypubFollow,
@@ -704,6 +1219,12 @@ pub enum JobType {
     ActivitypubRefresher,
     VideoRedundancy,
     VideoLiveEnding,
+    VideoTranscription,
+    /**
+     * Job type added by a newer PeerTube version.
+     */
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -724,3 +1245,267 @@ pub struct Runner {
     pub updated_at: chrono::DateTime<chrono::offset::Utc>,
     pub last_contact: chrono::DateTime<chrono::offset::Utc>,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RunnerJobType {
+    VodWebVideoTranscoding,
+    VodHlsTranscoding,
+    VodAudioMergeTranscoding,
+    LiveRtmpHlsTranscoding,
+    VideoStudioTranscoding,
+}
+
+/**
+ * Work to do, depending on the job type.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "kebab-case")]
+pub enum RunnerJobPayload {
+    VodWebVideoTranscoding(VodWebVideoTranscodingPayload),
+    VodHlsTranscoding(VodHlsTranscodingPayload),
+    VodAudioMergeTranscoding(VodAudioMergeTranscodingPayload),
+    LiveRtmpHlsTranscoding(LiveRtmpHlsTranscodingPayload),
+    VideoStudioTranscoding(VideoStudioTranscodingPayload),
+    /**
+     * Job type unknown to this crate, or payload not matching its type.
+     */
+    #[serde(untagged)]
+    Other {
+        r#type: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl RunnerJobPayload {
+    pub fn r#type(&self) -> Option<RunnerJobType> {
+        let r#type = match self {
+            Self::VodWebVideoTranscoding(_) => RunnerJobType::VodWebVideoTranscoding,
+            Self::VodHlsTranscoding(_) => RunnerJobType::VodHlsTranscoding,
+            Self::VodAudioMergeTranscoding(_) => RunnerJobType::VodAudioMergeTranscoding,
+            Self::LiveRtmpHlsTranscoding(_) => RunnerJobType::LiveRtmpHlsTranscoding,
+            Self::VideoStudioTranscoding(_) => RunnerJobType::VideoStudioTranscoding,
+            Self::Other { .. } => return None,
+        };
+
+        Some(r#type)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VodInput {
+    /**
+     * Download it with `Jobs::download`.
+     */
+    pub video_file_url: String,
+    #[serde(default)]
+    pub separated_audio_file_url: Vec<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct TranscodingOutput {
+    pub resolution: u32,
+    pub fps: f32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VodWebVideoTranscodingPayload {
+    pub input: VodInput,
+    pub output: TranscodingOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VodHlsTranscodingPayload {
+    pub input: VodInput,
+    pub output: VodHlsOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VodHlsOutput {
+    pub resolution: u32,
+    pub fps: f32,
+    #[serde(default)]
+    pub delete_web_video_files: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VodAudioMergeTranscodingPayload {
+    pub input: AudioMergeInput,
+    pub output: TranscodingOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioMergeInput {
+    pub audio_file_url: String,
+    pub preview_file_url: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct LiveRtmpHlsTranscodingPayload {
+    pub input: LiveInput,
+    pub output: LiveOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInput {
+    pub rtmp_url: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveOutput {
+    pub to_transcode: Vec<TranscodingOutput>,
+    /**
+     * In seconds.
+     */
+    pub segment_duration: u32,
+    pub segment_list_size: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoStudioTranscodingPayload {
+    pub input: VodInput,
+    pub tasks: Vec<StudioTaskPayload>,
+}
+
+/**
+ * Edition to apply, files are given as URLs to download with
+ * `Jobs::download`.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "name", content = "options", rename_all = "kebab-case")]
+pub enum StudioTaskPayload {
+    AddIntro {
+        file: String,
+    },
+    AddOutro {
+        file: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    AddWatermark {
+        file: String,
+        watermark_size_ratio: Option<f32>,
+        horizontal_margin_ratio: Option<f32>,
+        vertical_margin_ratio: Option<f32>,
+    },
+    Cut {
+        start: Option<u32>,
+        end: Option<u32>,
+    },
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct AvailableJobs {
+    #[serde(rename = "availableJobs")]
+    pub available_jobs: Vec<AvailableJob>,
+}
+
+/**
+ * Job waiting for a runner, it must be accepted before being processed.
+ */
+#[derive(Debug, serde::Deserialize)]
+pub struct AvailableJob {
+    pub uuid: String,
+    #[serde(flatten)]
+    pub payload: RunnerJobPayload,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerJob {
+    pub uuid: String,
+    #[serde(flatten)]
+    pub payload: RunnerJobPayload,
+    pub state: RunnerJobStateInfo,
+    pub progress: Option<u32>,
+    pub priority: i32,
+    pub failures: u32,
+    pub error: Option<String>,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+    pub started_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+    /**
+     * Runner processing the job, only given to administrators.
+     */
+    pub runner: Option<RunnerSummary>,
+    /**
+     * Job to complete before this one can start.
+     */
+    pub parent: Option<ParentRunnerJob>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RunnerSummary {
+    pub id: u32,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ParentRunnerJob {
+    pub uuid: String,
+    pub r#type: String,
+    pub state: RunnerJobStateInfo,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RunnerJobStateInfo {
+    pub id: RunnerJobState,
+    pub label: String,
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, serde_repr::Deserialize_repr, serde_repr::Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RunnerJobState {
+    Pending = 1,
+    Processing = 2,
+    Completed = 3,
+    Errored = 4,
+    WaitingForParentJob = 5,
+    Cancelled = 6,
+    ParentErrored = 7,
+    ParentCancelled = 8,
+    Completing = 9,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct Accepted {
+    pub job: AcceptedRunnerJob,
+}
+
+/**
+ * Job accepted by this runner, the job token authenticates the following
+ * requests about it.
+ */
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedRunnerJob {
+    #[serde(flatten)]
+    pub job: RunnerJob,
+    pub job_token: String,
+}
+
+impl std::ops::Deref for AcceptedRunnerJob {
+    type Target = RunnerJob;
+
+    fn deref(&self) -> &Self::Target {
+        &self.job
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerRegistrationToken {
+    pub id: u32,
+    pub registration_token: String,
+    pub registered_runners_count: u32,
+    pub created_at: chrono::DateTime<chrono::offset::Utc>,
+    pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
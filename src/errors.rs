@@ -9,5 +9,217 @@ pub enum Error {
     #[error("{0}")]
     Json(#[from] serde_json::Error),
     #[error("{0}")]
-    Peertube(String),
+    Peertube(Box<PeertubeError>),
+    #[error("{0}")]
+    Caption(#[from] crate::captions::ParseError),
+    #[error("{0}")]
+    Header(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("No session, call Api::auth first")]
+    NotAuthenticated,
+    #[error("The video reached the {0:?} state")]
+    VideoState(crate::data::VideoState),
+    #[error("{0}")]
+    Timeout(#[from] tokio::time::error::Elapsed),
+    #[error("Unexpected response: {0}")]
+    UnexpectedResponse(String),
+}
+
+impl Error {
+    /**
+     * HTTP status of the failed response, if any.
+     */
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Http(err) => err.status(),
+            Self::Peertube(err) => Some(err.status),
+            _ => None,
+        }
+    }
+
+    /**
+     * PeerTube specific error code, if any.
+     */
+    pub fn code(&self) -> Option<&ErrorCode> {
+        match self {
+            Self::Peertube(err) => err.code.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_bad_request(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::BAD_REQUEST)
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::UNAUTHORIZED)
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::FORBIDDEN)
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::NOT_FOUND)
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::CONFLICT)
+    }
+
+    /**
+     * The user has two-factor authentication enabled, see
+     * `Api::auth_with_otp`.
+     */
+    pub fn is_missing_two_factor(&self) -> bool {
+        self.code() == Some(&ErrorCode::MissingTwoFactor)
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+    }
+}
+
+impl From<PeertubeError> for Error {
+    fn from(err: PeertubeError) -> Self {
+        Self::Peertube(Box::new(err))
+    }
+}
+
+/**
+ * Error returned by a PeerTube instance, as a RFC 7807 problem details
+ * object.
+ */
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeertubeError {
+    #[serde(skip)]
+    pub status: reqwest::StatusCode,
+    pub r#type: Option<String>,
+    pub title: Option<String>,
+    pub detail: Option<String>,
+    pub instance: Option<String>,
+    pub code: Option<ErrorCode>,
+    #[serde(default, rename = "invalid-params")]
+    pub invalid_params: std::collections::HashMap<String, InvalidParam>,
+    /**
+     * Raw response body.
+     */
+    #[serde(skip)]
+    pub body: String,
+}
+
+impl PeertubeError {
+    pub(crate) async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
+        let status = response.status();
+        let body = response.text().await?;
+
+        Ok(Self::new(status, body))
+    }
+
+    pub(crate) fn new(status: reqwest::StatusCode, body: String) -> Self {
+        let error = serde_json::from_str::<Self>(&body).unwrap_or_default();
+
+        Self {
+            status,
+            body,
+            ..error
+        }
+    }
+}
+
+impl std::fmt::Display for PeertubeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.detail.as_ref().or(self.title.as_ref()) {
+            Some(message) => write!(f, "{}: {message}", self.status),
+            None if self.body.is_empty() => write!(f, "{}", self.status),
+            None => write!(f, "{}: {}", self.status, self.body),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct InvalidParam {
+    pub msg: String,
+    #[serde(alias = "param")]
+    pub path: Option<String>,
+    pub location: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    AccountApprovalRejected,
+    AccountWaitingForApproval,
+    CommentNotAssociatedToVideo,
+    DoesNotRespectFollowConstraints,
+    IncorrectFilesInTorrent,
+    IncorrectVideoPassword,
+    InvalidClient,
+    InvalidGrant,
+    InvalidToken,
+    InvalidTwoFactor,
+    LiveConflictingPermanentAndSaveReplay,
+    LiveNotAllowingReplay,
+    LiveNotEnabled,
+    MaxFileSizeReached,
+    MaxInstanceLivesLimitReached,
+    MaxUserLivesLimitReached,
+    MaxUserVideoQuotaExceededForImport,
+    MissingTwoFactor,
+    QuotaReached,
+    RunnerJobNotInPendingState,
+    RunnerJobNotInProcessingState,
+    TooManyFollows,
+    UnknownRunnerToken,
+    VideoAlreadyBeingTranscoded,
+    VideoRequiresPassword,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn problem() {
+        let body = r#"{
+            "type": "https://docs.joinpeertube.org/api-rest-reference.html#section/Errors/invalid_token",
+            "detail": "Token is invalid.",
+            "status": 401,
+            "code": "invalid_token",
+            "invalid-params": {
+                "name": { "msg": "Should have a valid name", "path": "name", "location": "body" }
+            }
+        }"#;
+
+        let error = crate::PeertubeError::new(reqwest::StatusCode::UNAUTHORIZED, body.to_string());
+
+        assert_eq!(error.code, Some(crate::ErrorCode::InvalidToken));
+        assert_eq!(error.invalid_params["name"].msg, "Should have a valid name");
+        assert_eq!(error.to_string(), "401 Unauthorized: Token is invalid.");
+        assert!(crate::Error::from(error).is_unauthorized());
+    }
+
+    #[test]
+    fn unknown_code() {
+        let error = crate::PeertubeError::new(
+            reqwest::StatusCode::BAD_REQUEST,
+            r#"{"code": "something_new"}"#.to_string(),
+        );
+
+        assert_eq!(
+            error.code,
+            Some(crate::ErrorCode::Other("something_new".to_string()))
+        );
+    }
+
+    #[test]
+    fn not_json() {
+        let error = crate::PeertubeError::new(
+            reqwest::StatusCode::NOT_FOUND,
+            "Cannot GET /api/v1/foo".to_string(),
+        );
+
+        assert!(crate::Error::from(error).is_not_found());
+    }
 }
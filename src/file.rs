@@ -0,0 +1,184 @@
+use futures_util::TryStreamExt;
+use tokio::io::AsyncReadExt;
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+/**
+ * File content sent as a multipart part, read lazily.
+ *
+ * The MIME type is guessed from the file name extension and can be
+ * overridden with `File::mime`.
+ */
+pub struct File {
+    name: String,
+    mime: String,
+    body: reqwest::Body,
+    length: Option<u64>,
+}
+
+impl File {
+    /**
+     * Streams a file from disk.
+     */
+    pub fn path<P: AsRef<std::path::Path>>(path: P) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let length = file.metadata()?.len();
+        let name = path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let file = Self::new(&name, tokio::fs::File::from_std(file).into(), Some(length));
+
+        Ok(file)
+    }
+
+    /**
+     * Sends in-memory content, images MIME type is detected from the content
+     * if the name has no known extension.
+     */
+    pub fn bytes<B: Into<Vec<u8>>>(name: &str, bytes: B) -> Self {
+        let bytes = bytes.into();
+        let length = bytes.len() as u64;
+        let mime = crate::mime::sniff(&bytes);
+
+        let file = Self::new(name, bytes.into(), Some(length));
+
+        if file.mime == crate::mime::DEFAULT {
+            file.mime(mime)
+        } else {
+            file
+        }
+    }
+
+    /**
+     * Streams the content of a reader, `length` should be given when known.
+     */
+    pub fn reader<R>(name: &str, reader: R, length: Option<u64>) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + Unpin + 'static,
+    {
+        let stream = futures_util::stream::try_unfold(reader, |mut reader| async move {
+            let mut buffer = Vec::with_capacity(READ_BUFFER_SIZE);
+            let size = (&mut reader)
+                .take(READ_BUFFER_SIZE as u64)
+                .read_to_end(&mut buffer)
+                .await?;
+
+            Ok::<_, std::io::Error>((size > 0).then_some((buffer, reader)))
+        });
+
+        Self::new(name, reqwest::Body::wrap_stream(stream), length)
+    }
+
+    /**
+     * Streams chunks of bytes, `length` should be given when known.
+     */
+    pub fn stream<S>(name: &str, stream: S, length: Option<u64>) -> Self
+    where
+        S: futures_util::TryStream + Send + 'static,
+        S::Ok: Into<bytes::Bytes>,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let stream = stream.map_ok(Into::into).map_err(Into::into);
+
+        Self::new(name, reqwest::Body::wrap_stream(stream), length)
+    }
+
+    /**
+     * Overrides the guessed MIME type.
+     */
+    pub fn mime(mut self, mime: &str) -> Self {
+        self.mime = mime.to_string();
+        self
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn new(name: &str, body: reqwest::Body, length: Option<u64>) -> Self {
+        Self {
+            name: name.to_string(),
+            mime: crate::mime::guess(name).to_string(),
+            body,
+            length,
+        }
+    }
+
+    pub(crate) fn into_part(self) -> crate::Result<reqwest::multipart::Part> {
+        let part = match self.length {
+            Some(length) => reqwest::multipart::Part::stream_with_length(self.body, length),
+            None => reqwest::multipart::Part::stream(self.body),
+        };
+
+        let part = part.file_name(self.name).mime_str(&self.mime)?;
+
+        Ok(part)
+    }
+}
+
+/**
+ * Conversion into a `File`, implemented for paths.
+ */
+pub trait IntoFile {
+    fn into_file(self) -> crate::Result<File>;
+}
+
+impl IntoFile for File {
+    fn into_file(self) -> crate::Result<File> {
+        Ok(self)
+    }
+}
+
+impl IntoFile for &str {
+    fn into_file(self) -> crate::Result<File> {
+        File::path(self)
+    }
+}
+
+impl IntoFile for &std::path::Path {
+    fn into_file(self) -> crate::Result<File> {
+        File::path(self)
+    }
+}
+
+impl IntoFile for std::path::PathBuf {
+    fn into_file(self) -> crate::Result<File> {
+        File::path(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn mime() {
+        let file = crate::File::path("fixtures/avatar.png").unwrap();
+
+        assert_eq!(file.name, "avatar.png");
+        assert_eq!(file.mime, "image/png");
+        assert_eq!(
+            file.length,
+            Some(std::fs::metadata("fixtures/avatar.png").unwrap().len())
+        );
+    }
+
+    #[test]
+    fn reader() {
+        let file = crate::File::reader("video.webm", tokio::io::empty(), None);
+
+        assert_eq!(file.mime, "video/webm");
+        assert!(file.into_part().is_ok());
+    }
+
+    #[test]
+    fn bytes() {
+        let file = crate::File::bytes(
+            "thumbnailfile",
+            std::fs::read("fixtures/avatar.png").unwrap(),
+        );
+
+        assert_eq!(file.mime, "image/png");
+    }
+}
@@ -1,12 +1,28 @@
 #![warn(warnings)]
 
+pub mod captions;
 pub mod data;
 pub mod param;
 pub mod services;
+pub mod sessions;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(any(test, feature = "testing"))]
+pub mod totp;
 
+mod builder;
 mod errors;
+mod file;
+mod mime;
+mod paginator;
+mod retry;
+mod session;
 
+pub use builder::ApiBuilder;
 pub use errors::*;
+pub use file::{File, IntoFile};
+pub use paginator::Paginator;
+pub use retry::{Attempt, ExponentialBackoff, Retry};
 
 #[derive(Debug, serde::Deserialize)]
 pub struct Pager<T> {
@@ -17,12 +33,76 @@ pub struct Pager<T> {
 #[derive(Clone)]
 struct Config {
     base_url: String,
+    client: reqwest::Client,
+    retry: Option<std::sync::Arc<dyn Retry>>,
+    session: std::sync::Arc<session::Session>,
+}
+
+/**
+ * Credentials of a request.
+ *
+ * Service methods accept a `&data::Token`, or `Auth::Session` to use the
+ * credentials of the last successful `Api::auth` call:
+ *
+ * ```no_run
+ * # async fn f() -> peertube::Result {
+ * let api = peertube::Api::new("https://peertube.example.org");
+ * api.auth("root", "password").await?;
+ *
+ * let me = api.me.info(peertube::Auth::Session).await?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Clone, Debug, Default)]
+pub enum Auth {
+    #[default]
+    Anonymous,
+    /**
+     * Token of the session opened by `Api::auth`, refreshed as needed.
+     */
+    Session,
+    Token(data::Token),
+}
+
+impl Auth {
+    /**
+     * Token to send, the most recent one for tokens issued by the session.
+     */
+    pub(crate) async fn token(self, config: &Config) -> crate::Result<Option<data::Token>> {
+        match self {
+            Self::Anonymous => Ok(None),
+            Self::Session => match config.session.token(config).await? {
+                Some(token) => Ok(Some(token)),
+                None => Err(Error::NotAuthenticated),
+            },
+            Self::Token(token) => config.session.resolve(config, &token).await.map(Some),
+        }
+    }
+}
+
+impl From<&data::Token> for Auth {
+    fn from(token: &data::Token) -> Self {
+        Self::Token(token.clone())
+    }
+}
+
+impl From<Option<&data::Token>> for Auth {
+    fn from(token: Option<&data::Token>) -> Self {
+        token.map_or(Self::Anonymous, Self::from)
+    }
+}
+
+impl From<&Auth> for Auth {
+    fn from(auth: &Auth) -> Self {
+        auth.clone()
+    }
 }
 
 struct Request<S: serde::Serialize> {
     path: String,
     params: Params<S>,
-    auth: Option<data::Token>,
+    auth: Auth,
 }
 
 enum Params<S: serde::Serialize> {
@@ -30,6 +110,7 @@ enum Params<S: serde::Serialize> {
     Json(S),
     Form(S),
     Multipart(S, reqwest::multipart::Form),
+    Bytes(Vec<u8>),
     None,
 }
 
@@ -46,50 +127,121 @@ impl<S: serde::Serialize> Params<S> {
         Ok(Self::Multipart(params, form))
     }
 
-    fn upload(params: S, name: &str, file: &str) -> Result<Self> {
-        let part =
-            reqwest::multipart::Part::bytes(std::fs::read(file)?).file_name(file.to_string());
+    fn upload<F: IntoFile>(params: S, name: &str, file: F) -> Result<Self> {
+        Self::multipart(params)?.file(name, file)
+    }
+
+    fn file<F: IntoFile>(self, name: &str, file: F) -> Result<Self> {
+        let Self::Multipart(params, form) = self else {
+            unreachable!();
+        };
+
+        let part = file.into_file()?.into_part()?;
+
+        Ok(Self::Multipart(params, form.part(name.to_string(), part)))
+    }
+}
+
+impl<S: serde::Serialize + param::Files> Params<S> {
+    /**
+     * Multipart params, with the files referenced by `params` as file parts.
+     */
+    fn with_files(params: S) -> Result<Self> {
+        let files = params.files()?;
+
+        Self::multipart_files(params, files)
+    }
+
+    /**
+     * JSON params, or multipart params like `with_files` if `params`
+     * references files.
+     */
+    fn json_or_files(params: S) -> Result<Self> {
+        let files = params.files()?;
 
-        let form = reqwest::multipart::Form::new().part(name.to_string(), part);
+        if files.is_empty() {
+            Ok(Self::Json(params))
+        } else {
+            Self::multipart_files(params, files)
+        }
+    }
+
+    fn multipart_files(params: S, files: Vec<(&'static str, File)>) -> Result<Self> {
+        let mut form = reqwest::multipart::Form::new();
+
+        for (name, file) in files {
+            form = form.part(name, file.into_part()?);
+        }
 
         Ok(Self::Multipart(params, form))
     }
 }
 
+/**
+ * Flattens the fields of `params`, see `multipart_fields`.
+ *
+ * Unlike `Params::Query`, arrays are supported in query strings.
+ */
+fn fields<S: serde::Serialize>(params: &S) -> Result<Vec<(String, String)>> {
+    let fields = match serde_json::to_value(params)? {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .flat_map(|(k, v)| multipart_fields(k, v))
+            .collect(),
+        serde_json::Value::Null => Vec::new(),
+        _ => unimplemented!(),
+    };
+
+    Ok(fields)
+}
+
+/**
+ * Flattens a value into multipart text fields, arrays and objects use the
+ * `key[index]` and `key[field]` notations.
+ */
+fn multipart_fields(key: String, value: serde_json::Value) -> Vec<(String, String)> {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => Vec::new(),
+        Value::String(s) => vec![(key, s)],
+        Value::Array(array) => array
+            .into_iter()
+            .enumerate()
+            .flat_map(|(n, v)| multipart_fields(format!("{key}[{n}]"), v))
+            .collect(),
+        Value::Object(map) => map
+            .into_iter()
+            .flat_map(|(k, v)| multipart_fields(format!("{key}[{k}]"), v))
+            .collect(),
+        v => vec![(key, v.to_string())],
+    }
+}
+
 impl<S: serde::Serialize> Request<S> {
     fn into_request(
         self,
         method: reqwest::Method,
-        base_url: &str,
+        config: &Config,
     ) -> crate::Result<reqwest::RequestBuilder> {
-        let url = format!("{base_url}/api/v1{}", self.path);
-        let client = reqwest::Client::new();
-        let mut request = client.request(method, url);
+        let url = format!("{}/api/v1{}", config.base_url, self.path);
+        let mut request = config.client.request(method, url);
 
         request = match self.params {
             Params::Query(params) => request.query(&params),
             Params::Json(params) => request.json(&params),
             Params::Form(params) => request.form(&params),
             Params::Multipart(params, mut form) => {
-                match serde_json::to_value(&params)? {
-                    serde_json::Value::Object(map) => {
-                        for (k, v) in map.iter() {
-                            form = form.text(k.to_string(), v.to_string());
-                        }
-                    }
-                    serde_json::Value::Null => (),
-                    _ => unimplemented!(),
-                };
+                for (k, v) in fields(&params)? {
+                    form = form.text(k, v);
+                }
 
                 request.multipart(form)
             }
+            Params::Bytes(bytes) => request.body(bytes),
             Params::None => request,
         };
 
-        if let Some(auth) = self.auth {
-            request = request.bearer_auth(auth.access_token);
-        }
-
         Ok(request)
     }
 }
@@ -105,7 +257,7 @@ impl From<String> for Request<()> {
         Self {
             path,
             params: Params::None,
-            auth: None,
+            auth: Auth::Anonymous,
         }
     }
 }
@@ -129,10 +281,16 @@ pub struct Api {
 
 impl Api {
     pub fn new(base_url: &str) -> Self {
-        let conf = Config {
-            base_url: base_url.to_string(),
-        };
+        Self::builder(base_url)
+            .build()
+            .expect("Unable to build the HTTP client")
+    }
 
+    pub fn builder(base_url: &str) -> ApiBuilder {
+        ApiBuilder::new(base_url)
+    }
+
+    fn from_config(conf: Config) -> Self {
         Self {
             abuses: services::Abuses::new(&conf),
             accounts: services::Accounts::new(&conf),
@@ -152,11 +310,56 @@ impl Api {
     }
 
     pub async fn auth(&self, username: &str, password: &str) -> crate::Result<data::Token> {
+        self.login(username, password, None).await
+    }
+
+    /**
+     * Authenticates a user with two-factor authentication enabled, `otp` is
+     * the current code of their authenticator, see `totp::code` with the
+     * `testing` feature.
+     */
+    pub async fn auth_with_otp(
+        &self,
+        username: &str,
+        password: &str,
+        otp: &str,
+    ) -> crate::Result<data::Token> {
+        self.login(username, password, Some(otp)).await
+    }
+
+    /**
+     * Authenticates a user, `otp` is only called to get a two-factor code if
+     * the instance asks for one.
+     */
+    pub async fn auth_with<F, Fut>(
+        &self,
+        username: &str,
+        password: &str,
+        otp: F,
+    ) -> crate::Result<data::Token>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = String>,
+    {
+        match self.auth(username, password).await {
+            Err(err) if err.is_missing_two_factor() => {
+                self.auth_with_otp(username, password, &otp().await).await
+            }
+            result => result,
+        }
+    }
+
+    async fn login(
+        &self,
+        username: &str,
+        password: &str,
+        otp: Option<&str>,
+    ) -> crate::Result<data::Token> {
         let oauth_clients: data::OauthClient =
             Self::get(&self.conf, "/oauth-clients/local".into()).await?;
         let params = param::Auth {
-            client_id: oauth_clients.client_id,
-            client_secret: oauth_clients.client_secret,
+            client_id: oauth_clients.client_id.clone(),
+            client_secret: oauth_clients.client_secret.clone(),
             username: username.to_string(),
             password: password.to_string(),
             grant_type: "password".to_string(),
@@ -166,9 +369,49 @@ impl Api {
         let request = Request {
             path: "/users/token".to_string(),
             params: Params::Form(params),
-            auth: None,
+            auth: Auth::Anonymous,
         };
-        Self::post(&self.conf, request).await
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(otp) = otp {
+            headers.insert("x-peertube-otp", otp.parse()?);
+        }
+
+        let token: data::Token =
+            Self::request_with_headers(reqwest::Method::POST, &self.conf, request, headers).await?;
+
+        self.conf.session.set(oauth_clients, token.clone()).await;
+
+        Ok(token)
+    }
+
+    /**
+     * Revokes a token, the session of this client is forgotten if the token
+     * was issued by it, even before a refresh.
+     */
+    pub async fn logout(&self, token: &data::Token) -> crate::Result<()> {
+        let request = Request {
+            path: "/users/revoke-token".to_string(),
+            params: Params::none(),
+            auth: token.into(),
+        };
+
+        let revoked = Self::post::<data::Empty, _>(&self.conf, request).await?;
+        self.conf.session.forget(token).await;
+
+        revoked.into()
+    }
+
+    /**
+     * Returns the token obtained by the last successful `auth` call,
+     * transparently refreshed when it is about to expire.
+     *
+     * Tokens previously returned by `auth` or this method can still be passed
+     * to services: they are replaced by the most recent one before each request.
+     * Services can also be called with `Auth::Session` to never handle tokens.
+     */
+    pub async fn token(&self) -> crate::Result<Option<data::Token>> {
+        self.conf.session.token(&self.conf).await
     }
 
     pub(crate) async fn get<T: for<'de> serde::Deserialize<'de>, P: serde::Serialize>(
@@ -208,45 +451,187 @@ impl Api {
         config: &Config,
         request: Request<P>,
     ) -> crate::Result<T> {
-        let response = request
-            .into_request(method, &config.base_url)?
-            .send()
+        Self::request_with_headers(method, config, request, Default::default()).await
+    }
+
+    /**
+     * Like `request`, with additional headers.
+     */
+    pub(crate) async fn request_with_headers<
+        T: for<'de> serde::Deserialize<'de>,
+        P: serde::Serialize,
+    >(
+        method: reqwest::Method,
+        config: &Config,
+        request: Request<P>,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<T> {
+        let data = Self::send(method, config, request, headers)
+            .await?
+            .json()
             .await?;
 
-        if response.status().is_success() {
-            let data = response.json().await?;
+        Ok(data)
+    }
 
-            Ok(data)
-        } else {
-            let text = response.text().await?;
+    async fn send<P: serde::Serialize>(
+        method: reqwest::Method,
+        config: &Config,
+        request: Request<P>,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<reqwest::Response> {
+        let response = Self::execute(method, config, request, headers).await?;
 
-            Err(crate::Error::Peertube(text))
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(PeertubeError::from_response(response).await?.into())
         }
     }
+
+    /**
+     * Sends a request, the response status is not checked.
+     */
+    pub(crate) async fn execute<P: serde::Serialize>(
+        method: reqwest::Method,
+        config: &Config,
+        mut request: Request<P>,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<reqwest::Response> {
+        let mut auth = std::mem::take(&mut request.auth).token(config).await?;
+
+        let mut renewed = false;
+        let mut retries = 0;
+        let mut request = request
+            .into_request(method.clone(), config)?
+            .headers(headers);
+
+        let response = loop {
+            // Bodies streamed from a reader can't be sent twice
+            let retry = request.try_clone();
+
+            let result = match &auth {
+                Some(token) => request.bearer_auth(&token.access_token),
+                None => request,
+            }
+            .send()
+            .await;
+
+            let Some(retry) = retry else {
+                break result?;
+            };
+            request = retry;
+
+            if let Ok(response) = &result
+                && response.status() == reqwest::StatusCode::UNAUTHORIZED
+                && !renewed
+                && let Some(token) = &auth
+                && let Some(token) = config.session.renew(config, token).await?
+            {
+                auth = Some(token);
+                renewed = true;
+                continue;
+            }
+
+            let attempt = Attempt {
+                method: &method,
+                retries,
+                outcome: result.as_ref(),
+            };
+
+            match config.retry.as_ref().and_then(|x| x.delay(&attempt)) {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    retries += 1;
+                }
+                None => break result?,
+            }
+        };
+
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    pub(crate) fn instance() -> String {
-        std::env::var("INSTANCE").unwrap()
+    /**
+     * Instance used by a test, the mock server stops when this is dropped.
+     */
+    pub(crate) struct Instance {
+        pub url: String,
+        _server: Option<crate::testing::MockServer>,
+    }
+
+    /**
+     * Live instance given by the `INSTANCE` environment variable, or a mock
+     * server.
+     */
+    pub(crate) async fn instance() -> Instance {
+        dotenvy::dotenv().ok();
+
+        if let Ok(url) = std::env::var("INSTANCE") {
+            return Instance { url, _server: None };
+        }
+
+        let server = crate::testing::MockServer::start().await.unwrap();
+
+        Instance {
+            url: server.url(),
+            _server: Some(server),
+        }
     }
 
     pub(crate) fn username() -> String {
-        std::env::var("USERNAME").unwrap()
+        std::env::var("USERNAME").unwrap_or_else(|_| "root".to_string())
     }
 
     pub(crate) fn password() -> String {
-        std::env::var("PASSWORD").unwrap()
+        std::env::var("PASSWORD").unwrap_or_else(|_| "password".to_string())
     }
 
-    pub(crate) async fn api() -> (crate::Api, crate::data::Token) {
-        dotenvy::dotenv().ok();
+    /**
+     * `Api` on `instance`, keeping it alive.
+     */
+    pub(crate) struct Api {
+        api: crate::Api,
+        _instance: Instance,
+    }
+
+    impl std::ops::Deref for Api {
+        type Target = crate::Api;
+
+        fn deref(&self) -> &crate::Api {
+            &self.api
+        }
+    }
+
+    pub(crate) async fn api() -> (Api, crate::data::Token) {
         env_logger::try_init().ok();
 
-        let api = crate::Api::new(&instance());
+        let instance = instance().await;
+        let api = crate::Api::new(&instance.url);
         let token = api.auth(&username(), &password()).await.unwrap();
 
-        (api, token)
+        (
+            Api {
+                api,
+                _instance: instance,
+            },
+            token,
+        )
+    }
+
+    /**
+     * Mock server, with an `Api` authenticated on it.
+     */
+    pub(crate) async fn server() -> (crate::testing::MockServer, crate::Api, crate::data::Token) {
+        env_logger::try_init().ok();
+
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let api = server.api();
+        let token = api.auth("root", "password").await.unwrap();
+
+        (server, api, token)
     }
 
     #[tokio::test]
@@ -259,4 +644,198 @@ mod test {
 
         assert!(auth.is_ok());
     }
+
+    #[test]
+    fn multipart_fields() {
+        let params = crate::param::NewVideo {
+            channel_id: "1".to_string(),
+            name: Some("test".to_string()),
+            nsfw: Some(false),
+            tags: Some(vec!["foo".to_string(), "bar".to_string()]),
+            scheduled_update: Some(crate::data::ScheduledUpdate {
+                update_at: "2026-01-01T00:00:00.000Z".to_string(),
+                privacy: 1,
+            }),
+            thumbnailfile: Some("fixtures/avatar.png".to_string()),
+
+            ..Default::default()
+        };
+
+        let mut fields = Vec::new();
+
+        if let serde_json::Value::Object(map) = serde_json::to_value(&params).unwrap() {
+            for (k, v) in map {
+                fields.extend(crate::multipart_fields(k, v));
+            }
+        }
+
+        let fields = fields
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            fields,
+            [
+                ("channelId", "1"),
+                ("name", "test"),
+                ("nsfw", "false"),
+                ("scheduledUpdate[privacy]", "1"),
+                ("scheduledUpdate[updateAt]", "2026-01-01T00:00:00.000Z"),
+                ("tags[0]", "foo"),
+                ("tags[1]", "bar"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn token() {
+        let (api, token) = crate::test::api().await;
+
+        let current = api.token().await.unwrap();
+
+        assert_eq!(current.map(|x| x.refresh_token), Some(token.refresh_token));
+    }
+
+    #[tokio::test]
+    async fn session() {
+        let instance = instance().await;
+        let api = crate::Api::new(&instance.url);
+
+        let err = api.me.info(crate::Auth::Session).await.unwrap_err();
+        assert!(matches!(err, crate::Error::NotAuthenticated));
+
+        api.auth(&username(), &password()).await.unwrap();
+
+        let me = api.me.info(crate::Auth::Session).await;
+
+        assert!(me.is_ok());
+    }
+
+    /**
+     * Like `server`, with `/users/token` answering refresh requests with the
+     * `refreshed` access token, and password grants with a token expiring in
+     * `expires_in` seconds.
+     */
+    async fn refresh_server(
+        expires_in: u32,
+    ) -> (crate::testing::MockServer, crate::Api, crate::data::Token) {
+        let server = crate::testing::MockServer::start().await.unwrap();
+
+        server.mock_fn("POST", "/api/v1/users/token", move |request| {
+            let mut token = crate::testing::fixtures::token();
+
+            if request.text().contains("grant_type=refresh_token") {
+                token["access_token"] = "refreshed".into();
+            } else {
+                token["expires_in"] = expires_in.into();
+            }
+
+            crate::testing::Response::json(token)
+        });
+
+        let api = server.api();
+        let token = api.auth("root", "password").await.unwrap();
+
+        (server, api, token)
+    }
+
+    fn refreshes(server: &crate::testing::MockServer) -> usize {
+        server
+            .requests()
+            .iter()
+            .filter(|x| x.path == "/api/v1/users/token")
+            .filter(|x| x.text().contains("grant_type=refresh_token"))
+            .count()
+    }
+
+    #[tokio::test]
+    async fn refresh_after_unauthorized() {
+        let (server, api, token) = refresh_server(86400).await;
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        server.mock_fn("GET", "/api/v1/users/me", move |_| {
+            if counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                crate::testing::Response::empty().status(401)
+            } else {
+                crate::testing::Response::json(crate::testing::fixtures::user())
+            }
+        });
+
+        assert!(api.me.info(&token).await.is_ok());
+        assert_eq!(refreshes(&server), 1);
+
+        let requests = server.requests();
+        let replayed = requests
+            .iter()
+            .filter(|x| x.path == "/api/v1/users/me")
+            .map(|x| x.header("authorization").unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            replayed,
+            [
+                format!("Bearer {}", token.access_token).as_str(),
+                "Bearer refreshed",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_before_expiration() {
+        let (server, api, token) = refresh_server(0).await;
+
+        assert!(api.me.info(&token).await.is_ok());
+        assert_eq!(refreshes(&server), 1);
+
+        let request = server.requests().pop().unwrap();
+
+        assert_eq!(request.path, "/api/v1/users/me");
+        assert_eq!(request.header("authorization"), Some("Bearer refreshed"));
+    }
+
+    #[tokio::test]
+    async fn refresh_several_times() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let refreshes = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = refreshes.clone();
+
+        server.mock_fn("POST", "/api/v1/users/token", move |request| {
+            let mut token = crate::testing::fixtures::token();
+            token["expires_in"] = 0.into();
+
+            if request.text().contains("grant_type=refresh_token") {
+                let n = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                token["access_token"] = format!("refreshed-{n}").into();
+            }
+
+            crate::testing::Response::json(token)
+        });
+
+        let api = server.api();
+        let token = api.auth("root", "password").await.unwrap();
+
+        for n in 0..3 {
+            assert!(api.me.info(&token).await.is_ok());
+
+            let request = server.requests().pop().unwrap();
+            let expected = format!("Bearer refreshed-{n}");
+
+            assert_eq!(request.header("authorization"), Some(expected.as_str()));
+        }
+    }
+
+    #[tokio::test]
+    async fn logout_superseded() {
+        let (server, api, token) = refresh_server(0).await;
+
+        api.logout(&token).await.unwrap();
+
+        let request = server.requests().pop().unwrap();
+
+        assert_eq!(request.path, "/api/v1/users/revoke-token");
+        assert_eq!(request.header("authorization"), Some("Bearer refreshed"));
+        assert!(api.token().await.unwrap().is_none());
+    }
 }
@@ -0,0 +1,65 @@
+pub(crate) const DEFAULT: &str = "application/octet-stream";
+
+const TYPES: &[(&str, &str)] = &[
+    ("3gp", "video/3gpp"),
+    ("aac", "audio/aac"),
+    ("avi", "video/x-msvideo"),
+    ("flac", "audio/flac"),
+    ("flv", "video/x-flv"),
+    ("gif", "image/gif"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("m3u8", "application/vnd.apple.mpegurl"),
+    ("m4a", "audio/mp4"),
+    ("m4s", "video/iso.segment"),
+    ("m4v", "video/mp4"),
+    ("mkv", "video/x-matroska"),
+    ("mov", "video/quicktime"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("mpeg", "video/mpeg"),
+    ("mpg", "video/mpeg"),
+    ("oga", "audio/ogg"),
+    ("ogg", "audio/ogg"),
+    ("ogv", "video/ogg"),
+    ("png", "image/png"),
+    ("srt", "application/x-subrip"),
+    ("torrent", "application/x-bittorrent"),
+    ("ts", "video/mp2t"),
+    ("vtt", "text/vtt"),
+    ("wav", "audio/wav"),
+    ("webm", "video/webm"),
+    ("webp", "image/webp"),
+    ("wmv", "video/x-ms-wmv"),
+];
+
+/**
+ * Guesses the MIME type of a file from its extension.
+ */
+pub(crate) fn guess<P: AsRef<std::path::Path>>(path: P) -> &'static str {
+    let Some(extension) = path.as_ref().extension().and_then(|x| x.to_str()) else {
+        return DEFAULT;
+    };
+
+    TYPES
+        .iter()
+        .find(|(x, _)| x.eq_ignore_ascii_case(extension))
+        .map_or(DEFAULT, |(_, mime)| mime)
+}
+
+/**
+ * Guesses the MIME type of an image from its first bytes.
+ */
+pub(crate) fn sniff(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(b"\xFF\xD8\xFF") {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else {
+        DEFAULT
+    }
+}
@@ -0,0 +1,232 @@
+use futures_util::{StreamExt, future, stream};
+
+const DEFAULT_PAGE_SIZE: usize = 25;
+
+/**
+ * Turns a paged endpoint into a stream of items.
+ *
+ * ```no_run
+ * # async fn f(api: &peertube::Api) -> peertube::Result {
+ * use futures_util::TryStreamExt;
+ *
+ * let videos: Vec<_> = peertube::Paginator::new(|pagination| async move {
+ *     let params = peertube::param::Videos {
+ *         pagination,
+ *         ..Default::default()
+ *     };
+ *
+ *     api.videos.all(&params).await
+ * })
+ * .page_size(100)
+ * .into_stream()
+ * .try_collect()
+ * .await?;
+ * # Ok(())
+ * # }
+ * ```
+ *
+ * When the instance returns fewer items than requested on the first page
+ * because of its own maximum for the endpoint (100 by default), the next
+ * pages are requested with that size. A shorter page before the end is an
+ * error instead of silently skipped items.
+ */
+pub struct Paginator<F> {
+    fetch: F,
+    page_size: usize,
+    prefetch: usize,
+}
+
+impl<T, F, Fut> Paginator<F>
+where
+    F: FnMut(crate::param::Pagination) -> Fut,
+    Fut: Future<Output = crate::Result<crate::Pager<T>>>,
+{
+    pub fn new(fetch: F) -> Self {
+        Self {
+            fetch,
+            page_size: DEFAULT_PAGE_SIZE,
+            prefetch: 1,
+        }
+    }
+
+    /**
+     * Number of items requested per page.
+     */
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /**
+     * Number of pages fetched concurrently, once the total is known.
+     */
+    pub fn prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+
+    pub fn into_stream(self) -> impl futures_util::Stream<Item = crate::Result<T>> {
+        let Self {
+            mut fetch,
+            page_size,
+            prefetch,
+        } = self;
+
+        stream::once(async move {
+            match fetch(pagination(0, page_size)).await {
+                Ok(first) if first.data.is_empty() && first.total > 0 => {
+                    stream::once(future::ready(Err(short_page(0)))).right_stream()
+                }
+                Ok(first) => {
+                    // Page size capped by the instance
+                    let page_size = if first.data.len() < first.total {
+                        first.data.len().min(page_size)
+                    } else {
+                        page_size
+                    };
+
+                    let next = (page_size..first.total).step_by(page_size);
+                    let pages = stream::iter(next)
+                        .map(move |start| {
+                            let page = fetch(pagination(start, page_size));
+
+                            async move {
+                                let page = page.await?;
+
+                                if page.data.len() < page_size
+                                    && start + page.data.len() < page.total
+                                {
+                                    Err(short_page(start))
+                                } else {
+                                    Ok(page)
+                                }
+                            }
+                        })
+                        .buffered(prefetch);
+
+                    stream::once(future::ready(Ok(first)))
+                        .chain(pages)
+                        .left_stream()
+                }
+                Err(err) => stream::once(future::ready(Err(err))).right_stream(),
+            }
+        })
+        .flatten()
+        .flat_map(|page| match page {
+            Ok(page) => stream::iter(page.data.into_iter().map(Ok)).left_stream(),
+            Err(err) => stream::once(future::ready(Err(err))).right_stream(),
+        })
+    }
+}
+
+fn short_page(start: usize) -> crate::Error {
+    crate::Error::UnexpectedResponse(format!("missing items in the page starting at {start}"))
+}
+
+fn pagination(start: usize, count: usize) -> crate::param::Pagination {
+    crate::param::Pagination {
+        start: Some(start),
+        count: Some(count),
+
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn videos() {
+        let (api, _) = crate::test::api().await;
+        let api = &api;
+
+        let videos: crate::Result<Vec<_>> = crate::Paginator::new(|pagination| async move {
+            let params = crate::param::Videos {
+                pagination,
+                ..Default::default()
+            };
+
+            api.videos.all(&params).await
+        })
+        .page_size(2)
+        .prefetch(2)
+        .into_stream()
+        .try_collect()
+        .await;
+
+        assert!(videos.is_ok());
+    }
+
+    #[tokio::test]
+    async fn pages() {
+        let pages = std::sync::Mutex::new(Vec::new());
+
+        let items: Vec<usize> = crate::Paginator::new(|pagination| {
+            let start = pagination.start.unwrap();
+            let count = pagination.count.unwrap();
+
+            pages.lock().unwrap().push(start);
+
+            async move {
+                Ok(crate::Pager {
+                    total: 7,
+                    data: (start..(start + count).min(7)).collect(),
+                })
+            }
+        })
+        .page_size(3)
+        .into_stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, (0..7).collect::<Vec<_>>());
+        assert_eq!(*pages.lock().unwrap(), vec![0, 3, 6]);
+    }
+
+    #[tokio::test]
+    async fn capped_page_size() {
+        let items: Vec<usize> = crate::Paginator::new(|pagination| {
+            let start = pagination.start.unwrap();
+            // The instance returns at most 100 items
+            let count = pagination.count.unwrap().min(100);
+
+            async move {
+                Ok(crate::Pager {
+                    total: 300,
+                    data: (start..(start + count).min(300)).collect(),
+                })
+            }
+        })
+        .page_size(200)
+        .into_stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, (0..300).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn short_page() {
+        let items: crate::Result<Vec<usize>> = crate::Paginator::new(|pagination| {
+            let start = pagination.start.unwrap();
+            // An item is missing from the second page
+            let end = if start == 3 { 5 } else { start + 3 };
+
+            async move {
+                Ok(crate::Pager {
+                    total: 9,
+                    data: (start..end.min(9)).collect(),
+                })
+            }
+        })
+        .page_size(3)
+        .into_stream()
+        .try_collect()
+        .await;
+
+        assert!(matches!(items, Err(crate::Error::UnexpectedResponse(_))));
+    }
+}
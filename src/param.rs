@@ -78,6 +78,14 @@ pub(crate) struct Auth {
     pub password: String,
 }
 
+#[derive(Debug, Default, serde::Serialize)]
+pub(crate) struct RefreshToken {
+    pub client_id: String,
+    pub client_secret: String,
+    pub grant_type: String,
+    pub refresh_token: String,
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NewUser {
@@ -130,6 +138,49 @@ pub struct User {
     pub video_quota_daily: Option<i32>,
 }
 
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Block {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Registrations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Moderation {
+    /**
+     * Message sent to the user by email.
+     */
+    pub moderation_response: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_email_delivery: Option<bool>,
+}
+
+/**
+ * Action applied to each user by `Users::bulk`.
+ */
+#[derive(Clone, Debug)]
+pub enum UserAction {
+    Block {
+        reason: Option<String>,
+    },
+    Unblock,
+    Delete,
+    Quota {
+        video_quota: Option<i32>,
+        video_quota_daily: Option<i32>,
+    },
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Register {
@@ -142,6 +193,50 @@ pub struct Register {
     pub display_name: Option<String>,
 }
 
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CurrentPassword {
+    pub current_password: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConfirmTwoFactor {
+    pub request_token: String,
+    pub otp_token: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct Email {
+    pub email: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VerifyEmail {
+    pub verification_string: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_pending_email: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ResetPassword {
+    pub verification_string: String,
+    pub password: String,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrationRequest {
+    #[serde(flatten)]
+    pub register: Register,
+    /**
+     * Shown to moderators.
+     */
+    pub registration_reason: String,
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Channel {
@@ -192,6 +287,22 @@ pub struct Notification {
     pub unread: Option<bool>,
 }
 
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GenerateCaption {
+    pub force_transcription: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct VideoPasswords<'a> {
+    pub passwords: &'a [String],
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct Chapters<'a> {
+    pub chapters: &'a [crate::data::Chapter],
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 pub(crate) struct Notifications {
     pub ids: Vec<u32>,
@@ -228,6 +339,40 @@ pub(crate) struct History {
     pub before_date: chrono::DateTime<chrono::offset::Utc>,
 }
 
+/**
+ * Params containing files, sent as multipart file parts.
+ */
+pub(crate) trait Files {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>>;
+}
+
+impl<T: Files> Files for &T {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        (*self).files()
+    }
+}
+
+impl Files for () {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        Ok(Vec::new())
+    }
+}
+
+fn path(
+    name: &'static str,
+    path: &Option<String>,
+) -> crate::Result<Option<(&'static str, crate::File)>> {
+    path.as_ref()
+        .map(|x| Ok((name, crate::File::path(x)?)))
+        .transpose()
+}
+
+fn bytes(name: &'static str, bytes: &Option<Vec<u8>>) -> Option<(&'static str, crate::File)> {
+    bytes
+        .as_ref()
+        .map(|x| (name, crate::File::bytes(name, x.clone())))
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Video {
@@ -247,7 +392,7 @@ pub struct Video {
     pub nsfw: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub originally_published_at: Option<chrono::DateTime<chrono::offset::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub previewfile: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
@@ -257,10 +402,23 @@ pub struct Video {
     pub support: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub thumbnailfile: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub twait_transcoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_passwords: Option<Vec<String>>,
+}
+
+impl Files for Video {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        let files = [
+            path("previewfile", &self.previewfile)?,
+            path("thumbnailfile", &self.thumbnailfile)?,
+        ];
+
+        Ok(files.into_iter().flatten().collect())
+    }
 }
 
 #[derive(Debug, serde_repr::Serialize_repr)]
@@ -270,6 +428,10 @@ pub enum Privacy {
     Unlisted = 2,
     Private = 3,
     Internal = 4,
+    /**
+     * Set the passwords with `video_passwords` or `Passwords::replace`.
+     */
+    PasswordProtected = 5,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -298,7 +460,7 @@ pub struct NewVideo {
     pub nsfw: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub originally_published_at: Option<chrono::DateTime<chrono::offset::Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub previewfile: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
@@ -308,10 +470,36 @@ pub struct NewVideo {
     pub support: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub thumbnailfile: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub twait_transcoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_passwords: Option<Vec<String>>,
+}
+
+impl Files for NewVideo {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        let files = [
+            path("previewfile", &self.previewfile)?,
+            path("thumbnailfile", &self.thumbnailfile)?,
+        ];
+
+        Ok(files.into_iter().flatten().collect())
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ResumableUpload<P: serde::Serialize> {
+    #[serde(flatten)]
+    pub params: P,
+    pub filename: String,
+}
+
+impl<P: serde::Serialize + Files> Files for ResumableUpload<P> {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        self.params.files()
+    }
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -321,10 +509,19 @@ pub struct Import {
     pub video: NewVideo,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_url: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub torrentfile: Option<String>,
 }
 
+impl Files for Import {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        let mut files = self.video.files()?;
+        files.extend(path("torrentfile", &self.torrentfile)?);
+
+        Ok(files)
+    }
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Live {
@@ -335,18 +532,40 @@ pub struct Live {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permanent_live: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub previewfile: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub save_replay: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replay_settings: Option<crate::data::ReplaySettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_mode: Option<crate::data::LatencyMode>,
+    #[deprecated(note = "use `video.previewfile`")]
+    #[serde(skip)]
+    pub previewfile: Option<String>,
 }
 
-#[derive(Debug, serde::Serialize)]
+impl Files for Live {
+    #[allow(deprecated)]
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        let mut files = self.video.files()?;
+
+        if self.video.previewfile.is_none() {
+            files.extend(path("previewfile", &self.previewfile)?);
+        }
+
+        Ok(files)
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LiveSetting {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_replay: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permanent_live: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replay_settings: Option<crate::data::ReplaySettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_mode: Option<crate::data::LatencyMode>,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -382,12 +601,20 @@ pub struct Playlist {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub thumbnailfile: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_channel_id: Option<u32>,
 }
 
+impl Files for Playlist {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        Ok(bytes("thumbnailfile", &self.thumbnailfile)
+            .into_iter()
+            .collect())
+    }
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaylistSetting {
@@ -397,12 +624,20 @@ pub struct PlaylistSetting {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub thumbnailfile: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_channel_id: Option<u32>,
 }
 
+impl Files for PlaylistSetting {
+    fn files(&self) -> crate::Result<Vec<(&'static str, crate::File)>> {
+        Ok(bytes("thumbnailfile", &self.thumbnailfile)
+            .into_iter()
+            .collect())
+    }
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaylistElement {
@@ -422,11 +657,6 @@ pub struct Reorder {
     pub reorder_length: Option<u32>,
 }
 
-#[derive(Debug, serde::Serialize)]
-pub(crate) struct Elements {
-    pub video_ids: Vec<String>,
-}
-
 #[derive(Debug, serde::Serialize)]
 pub(crate) struct Ownership {
     pub username: String,
@@ -645,6 +875,7 @@ pub struct PluginSettings {
 }
 
 #[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Jobs {
     #[serde(flatten)]
     pub pagination: Pagination,
@@ -666,3 +897,226 @@ pub struct NewRunner {
 pub struct Runner {
     pub runner_token: String,
 }
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RequestJobs {
+    pub runner_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_types: Option<Vec<crate::data::RunnerJobType>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobToken {
+    pub runner_token: String,
+    pub job_token: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobUpdate {
+    #[serde(flatten)]
+    pub token: JobToken,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<LiveChunkFields>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LiveChunkFields {
+    pub r#type: &'static str,
+    pub video_chunk_filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution_playlist_filename: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobAbort {
+    #[serde(flatten)]
+    pub token: JobToken,
+    pub reason: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobError {
+    #[serde(flatten)]
+    pub token: JobToken,
+    pub message: String,
+}
+
+/**
+ * Segment produced by a live transcoding job.
+ */
+pub enum LiveChunk {
+    Add(Box<LiveChunkFiles>),
+    Remove { video_chunk_filename: String },
+}
+
+/**
+ * Files of a new live segment.
+ */
+pub struct LiveChunkFiles {
+    pub video_chunk: crate::File,
+    pub resolution_playlist: crate::File,
+    pub master_playlist: Option<crate::File>,
+}
+
+impl LiveChunk {
+    pub fn add(video_chunk: crate::File, resolution_playlist: crate::File) -> Self {
+        Self::Add(Box::new(LiveChunkFiles {
+            video_chunk,
+            resolution_playlist,
+            master_playlist: None,
+        }))
+    }
+}
+
+/**
+ * Result of a job, the variant must match the job type.
+ */
+pub enum RunnerJobSuccess {
+    VodWebVideoTranscoding {
+        video_file: crate::File,
+    },
+    VodHlsTranscoding {
+        video_file: crate::File,
+        resolution_playlist_file: crate::File,
+    },
+    VodAudioMergeTranscoding {
+        video_file: crate::File,
+    },
+    LiveRtmpHlsTranscoding,
+    VideoStudioTranscoding {
+        video_file: crate::File,
+    },
+}
+
+impl RunnerJobSuccess {
+    pub(crate) fn into_files(self) -> Vec<(&'static str, crate::File)> {
+        match self {
+            Self::VodWebVideoTranscoding { video_file }
+            | Self::VodAudioMergeTranscoding { video_file }
+            | Self::VideoStudioTranscoding { video_file } => {
+                vec![("payload[videoFile]", video_file)]
+            }
+            Self::VodHlsTranscoding {
+                video_file,
+                resolution_playlist_file,
+            } => vec![
+                ("payload[videoFile]", video_file),
+                ("payload[resolutionPlaylistFile]", resolution_playlist_file),
+            ],
+            Self::LiveRtmpHlsTranscoding => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerJobs {
+    #[serde(flatten)]
+    pub pagination: Pagination,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub state_one_of: Vec<crate::data::RunnerJobState>,
+}
+
+/**
+ * Video Studio task, see `StudioEdit`.
+ */
+#[derive(serde::Serialize)]
+#[serde(tag = "name", content = "options", rename_all = "kebab-case")]
+pub enum StudioTask {
+    /**
+     * Keeps the video between `start` and `end`, in seconds.
+     */
+    Cut {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        start: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        end: Option<u32>,
+    },
+    AddIntro {
+        #[serde(skip)]
+        file: crate::File,
+    },
+    AddOutro {
+        #[serde(skip)]
+        file: crate::File,
+    },
+    AddWatermark {
+        #[serde(skip)]
+        file: crate::File,
+    },
+}
+
+/**
+ * Video Studio edition, tasks are applied in order.
+ *
+ * ```no_run
+ * # fn f() -> peertube::Result {
+ * let edit = peertube::param::StudioEdit::default()
+ *     .cut(Some(5), Some(3600))
+ *     .add_intro("intro.mp4")?
+ *     .add_watermark("logo.png")?;
+ * # Ok(())
+ * # }
+ * ```
+ */
+#[derive(Default, serde::Serialize)]
+pub struct StudioEdit {
+    tasks: Vec<StudioTask>,
+}
+
+impl StudioEdit {
+    pub fn task(mut self, task: StudioTask) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    pub fn cut(self, start: Option<u32>, end: Option<u32>) -> Self {
+        self.task(StudioTask::Cut { start, end })
+    }
+
+    pub fn add_intro<F: crate::IntoFile>(self, file: F) -> crate::Result<Self> {
+        Ok(self.task(StudioTask::AddIntro {
+            file: file.into_file()?,
+        }))
+    }
+
+    pub fn add_outro<F: crate::IntoFile>(self, file: F) -> crate::Result<Self> {
+        Ok(self.task(StudioTask::AddOutro {
+            file: file.into_file()?,
+        }))
+    }
+
+    pub fn add_watermark<F: crate::IntoFile>(self, file: F) -> crate::Result<Self> {
+        Ok(self.task(StudioTask::AddWatermark {
+            file: file.into_file()?,
+        }))
+    }
+
+    /**
+     * Files of the tasks, as `tasks[n][options][file]` parts.
+     */
+    pub(crate) fn into_files(self) -> Vec<(String, crate::File)> {
+        self.tasks
+            .into_iter()
+            .enumerate()
+            .filter_map(|(n, task)| match task {
+                StudioTask::Cut { .. } => None,
+                StudioTask::AddIntro { file }
+                | StudioTask::AddOutro { file }
+                | StudioTask::AddWatermark { file } => {
+                    Some((format!("tasks[{n}][options][file]"), file))
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,329 @@
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/**
+ * Decides if and when a failed request is sent again.
+ */
+pub trait Retry: Send + Sync {
+    /**
+     * Returns the delay before the next attempt, or `None` to give up.
+     */
+    fn delay(&self, attempt: &Attempt<'_>) -> Option<Duration>;
+}
+
+/**
+ * Outcome of a request, passed to the retry policy.
+ */
+pub struct Attempt<'a> {
+    pub method: &'a reqwest::Method,
+    /**
+     * Number of retries already done for this request.
+     */
+    pub retries: u32,
+    pub outcome: std::result::Result<&'a reqwest::Response, &'a reqwest::Error>,
+}
+
+impl Attempt<'_> {
+    /**
+     * `true` if sending the same request twice has the same effect as sending
+     * it once.
+     */
+    pub fn is_idempotent(&self) -> bool {
+        use reqwest::Method;
+
+        [
+            Method::GET,
+            Method::HEAD,
+            Method::OPTIONS,
+            Method::PUT,
+            Method::DELETE,
+        ]
+        .contains(self.method)
+    }
+
+    /**
+     * `true` if the request was rejected before being processed by the server,
+     * so it can safely be sent again whatever its method.
+     */
+    pub fn is_rejected(&self) -> bool {
+        match self.outcome {
+            Ok(response) => response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS,
+            Err(err) => err.is_connect(),
+        }
+    }
+
+    /**
+     * `true` if the failure is likely temporary.
+     */
+    pub fn is_transient(&self) -> bool {
+        use reqwest::StatusCode;
+
+        match self.outcome {
+            Ok(response) => [
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ]
+            .contains(&response.status()),
+            Err(err) => err.is_connect() || err.is_timeout(),
+        }
+    }
+
+    /**
+     * Delay requested by the server with the `Retry-After` or rate limit
+     * headers.
+     */
+    pub fn retry_after(&self) -> Option<Duration> {
+        let headers = self.outcome.ok()?.headers();
+        let header = |name: &str| headers.get(name).and_then(|x| x.to_str().ok());
+
+        if let Some(retry_after) = header("retry-after") {
+            if let Ok(seconds) = retry_after.parse() {
+                return Some(Duration::from_secs(seconds));
+            }
+
+            if let Ok(date) = chrono::DateTime::parse_from_rfc2822(retry_after) {
+                return until(date.timestamp());
+            }
+        }
+
+        if let Some(seconds) = header("ratelimit-reset").and_then(|x| x.parse().ok()) {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        // Legacy header, contains a timestamp
+        header("x-ratelimit-reset")
+            .and_then(|x| x.parse().ok())
+            .and_then(until)
+    }
+}
+
+fn until(timestamp: i64) -> Option<Duration> {
+    let seconds = timestamp - chrono::Utc::now().timestamp();
+
+    Some(Duration::from_secs(seconds.max(0) as u64))
+}
+
+/**
+ * Retries transient failures with an exponentially growing delay.
+ *
+ * Non-idempotent requests (`POST`) are only retried if the server didn't
+ * process them, unless `retry_non_idempotent` is enabled.
+ */
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    max_retries: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_retry_after: Duration,
+    jitter: bool,
+    retry_non_idempotent: bool,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retry_after: Duration::from_secs(120),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /**
+     * Upper bound of the computed delay, a longer delay requested by the
+     * server is still honored up to `max_retry_after`.
+     */
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /**
+     * Longest delay requested by the server that is waited for, the request
+     * fails instead of retrying if the server asks to wait longer.
+     */
+    pub fn max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    /**
+     * Randomizes delays to avoid many clients retrying at the same time.
+     */
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    fn backoff(&self, retries: u32) -> Duration {
+        let delay = self
+            .initial_delay
+            .saturating_mul(2_u32.saturating_pow(retries))
+            .min(self.max_delay);
+
+        if self.jitter {
+            let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+            hasher.write_u32(retries);
+
+            delay.mul_f64((hasher.finish() % 1_000) as f64 / 1_000.)
+        } else {
+            delay
+        }
+    }
+}
+
+impl Retry for ExponentialBackoff {
+    fn delay(&self, attempt: &Attempt<'_>) -> Option<Duration> {
+        if attempt.retries >= self.max_retries || !attempt.is_transient() {
+            return None;
+        }
+
+        if !attempt.is_idempotent() && !attempt.is_rejected() && !self.retry_non_idempotent {
+            return None;
+        }
+
+        let backoff = self.backoff(attempt.retries);
+
+        match attempt.retry_after() {
+            Some(retry_after) if retry_after > self.max_retry_after => None,
+            Some(retry_after) => Some(retry_after.max(backoff)),
+            None => Some(backoff),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::retry::Retry;
+
+    fn response(status: u16, headers: &[(&str, &str)]) -> reqwest::Response {
+        let mut response = http::Response::builder().status(status);
+
+        for (name, value) in headers {
+            response = response.header(*name, *value);
+        }
+
+        response.body(String::new()).unwrap().into()
+    }
+
+    fn delay(method: reqwest::Method, response: &reqwest::Response) -> Option<std::time::Duration> {
+        let attempt = crate::Attempt {
+            method: &method,
+            retries: 0,
+            outcome: Ok(response),
+        };
+
+        crate::ExponentialBackoff::default()
+            .jitter(false)
+            .delay(&attempt)
+    }
+
+    #[test]
+    fn rate_limited() {
+        let response = response(429, &[("retry-after", "12")]);
+
+        assert_eq!(
+            delay(reqwest::Method::POST, &response),
+            Some(std::time::Duration::from_secs(12))
+        );
+    }
+
+    #[test]
+    fn retry_after_too_long() {
+        let response = response(429, &[("retry-after", "86400")]);
+
+        assert_eq!(delay(reqwest::Method::GET, &response), None);
+    }
+
+    #[test]
+    fn unavailable() {
+        let response = response(503, &[]);
+
+        assert_eq!(
+            delay(reqwest::Method::GET, &response),
+            Some(std::time::Duration::from_millis(500))
+        );
+        assert_eq!(delay(reqwest::Method::POST, &response), None);
+    }
+
+    #[test]
+    fn not_found() {
+        let response = response(404, &[]);
+
+        assert_eq!(delay(reqwest::Method::GET, &response), None);
+    }
+
+    #[test]
+    fn max_retries() {
+        let response = response(503, &[]);
+        let attempt = crate::Attempt {
+            method: &reqwest::Method::GET,
+            retries: 3,
+            outcome: Ok(&response),
+        };
+
+        assert_eq!(crate::ExponentialBackoff::default().delay(&attempt), None);
+    }
+
+    #[tokio::test]
+    async fn api() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let counter = calls.clone();
+
+        server.mock_fn("GET", "/api/v1/videos/*", move |_| {
+            if counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                crate::testing::Response::empty().status(503)
+            } else {
+                crate::testing::Response::json(crate::testing::fixtures::video_details())
+            }
+        });
+        server.mock(
+            "POST",
+            "/api/v1/videos/*/views",
+            crate::testing::Response::empty().status(503),
+        );
+
+        let retry = crate::ExponentialBackoff::default()
+            .jitter(false)
+            .initial_delay(std::time::Duration::from_millis(10));
+        let api = crate::Api::builder(&server.url())
+            .retry(retry)
+            .build()
+            .unwrap();
+
+        assert!(api.videos.get("1").await.is_ok());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        assert!(api.videos.add_view("1").await.is_err());
+
+        let posts = server
+            .requests()
+            .iter()
+            .filter(|x| x.method == "POST")
+            .count();
+        assert_eq!(posts, 1);
+    }
+}
@@ -12,15 +12,15 @@ impl Abuses {
     /**
      * List abuses.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Abuses,
     ) -> crate::Result<crate::Pager<crate::data::Abuse>> {
         let request = crate::Request {
             path: "/abuses".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,15 +29,15 @@ impl Abuses {
     /**
      * Report an abuse.
      */
-    pub async fn report(
+    pub async fn report<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Abuse,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: "/abuses".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -46,16 +46,16 @@ impl Abuses {
     /**
      * Update an abuse.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: u32,
         params: &crate::param::AbuseSetting,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/abuses/{id}"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -64,11 +64,11 @@ impl Abuses {
     /**
      * Delete an abuse.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, id: u32) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: u32) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/abuses/{id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -77,15 +77,15 @@ impl Abuses {
     /**
      * List messages of an abuse.
      */
-    pub async fn messages(
+    pub async fn messages<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: u32,
     ) -> crate::Result<crate::Pager<crate::data::AbuseMessage>> {
         let request = crate::Request {
             path: format!("/abuses/{id}/messages"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -94,9 +94,9 @@ impl Abuses {
     /**
      * Add message to an abuse.
      */
-    pub async fn add_message(
+    pub async fn add_message<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: u32,
         message: &str,
     ) -> crate::Result<crate::data::NewContent> {
@@ -107,7 +107,7 @@ impl Abuses {
         let request = crate::Request {
             path: format!("/abuses/{id}/messages"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -116,16 +116,16 @@ impl Abuses {
     /**
      * Delete an abuse message.
      */
-    pub async fn delete_message(
+    pub async fn delete_message<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: u32,
         message_id: u32,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/abuses/{id}/messages/{message_id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -30,7 +30,7 @@ impl Accounts {
         let request = crate::Request {
             path: format!("/accounts/{name}/videos"),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -46,7 +46,7 @@ impl Accounts {
         let request = crate::Request {
             path: "/accounts".to_string(),
             params: crate::Params::Query(pagination),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -63,7 +63,7 @@ impl Accounts {
         let request = crate::Request {
             path: format!("/accounts/{name}/video-channels"),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -72,16 +72,16 @@ impl Accounts {
     /**
      * List ratings of an account.
      */
-    pub async fn ratings(
+    pub async fn ratings<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         name: &str,
         params: &crate::param::Ratings,
     ) -> crate::Result<crate::Pager<crate::data::Channel>> {
         let request = crate::Request {
             path: format!("/accounts/{name}/ratings"),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
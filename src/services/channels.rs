@@ -19,15 +19,15 @@ impl Channels {
     /**
      * Create a video channel.
      */
-    pub async fn create(
+    pub async fn create<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Channel,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: "/video-channels".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -43,16 +43,16 @@ impl Channels {
     /**
      * Update a video channel.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         handle: &str,
         params: &crate::param::ChannelSetting,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-channels/{handle}"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -61,11 +61,11 @@ impl Channels {
     /**
      * Delete a video channel.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, handle: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, handle: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-channels/{handle}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -82,7 +82,7 @@ impl Channels {
         let request = crate::Request {
             path: format!("/video-channels/{handle}/videos"),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -91,16 +91,16 @@ impl Channels {
     /**
      * Update channel avatar.
      */
-    pub async fn update_avatar(
+    pub async fn update_avatar<A: Into<crate::Auth>, F: crate::IntoFile>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         handle: &str,
-        avatarfile: &str,
+        avatarfile: F,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-channels/{handle}/avatar/pick"),
             params: crate::Params::upload((), "avatarfile", avatarfile)?,
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -111,15 +111,15 @@ impl Channels {
     /**
      * Delete channel avatar.
      */
-    pub async fn delete_avatar(
+    pub async fn delete_avatar<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         handle: &str,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-channels/{handle}/avatar"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -26,14 +26,14 @@ impl Config {
     /**
      * Get instance runtime configuration.
      */
-    pub async fn custom(
+    pub async fn custom<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
     ) -> crate::Result<crate::data::config::Custom> {
         let request = crate::Request {
             path: "/config/custom".to_string(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -42,9 +42,9 @@ impl Config {
     /**
      * Set instance runtime configuration.
      */
-    pub async fn set(
+    pub async fn set<A: Into<crate::Auth>>(
         &self,
-        _auth: &crate::data::Token,
+        _auth: A,
         _config: crate::data::config::Custom,
     ) -> crate::Result<()> {
         todo!()
@@ -53,11 +53,11 @@ impl Config {
     /**
      * Delete instance runtime configuration.
      */
-    pub async fn delete(&self, auth: &crate::data::Token) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<()> {
         let request = crate::Request {
             path: "/config/custom".to_string(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -12,20 +12,69 @@ impl Jobs {
     /**
      * List instance jobs.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         state: crate::data::JobState,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::Job>> {
         let request = crate::Request {
             path: format!("/jobs/{state}"),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
     }
+
+    /**
+     * List instance jobs, filtered by type.
+     */
+    pub async fn search<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        state: crate::data::JobState,
+        params: &crate::param::Jobs,
+    ) -> crate::Result<crate::Pager<crate::data::Job>> {
+        let request = crate::Request {
+            path: format!("/jobs/{state}"),
+            params: crate::Params::Query(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Most recent transcription job of a video, see
+     * `Captions::generate`.
+     *
+     * Only the last 100 transcription jobs are searched.
+     */
+    pub async fn transcription<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_uuid: &str,
+    ) -> crate::Result<Option<crate::data::Job>> {
+        let params = crate::param::Jobs {
+            pagination: crate::param::Pagination {
+                count: Some(100),
+                sort: Some("-createdAt".to_string()),
+
+                ..Default::default()
+            },
+            job_type: Some(crate::data::JobType::VideoTranscription),
+        };
+
+        let jobs = self
+            .search(auth, crate::data::JobState::All, &params)
+            .await?;
+
+        Ok(jobs
+            .data
+            .into_iter()
+            .find(|x| x.video_uuid() == Some(video_uuid)))
+    }
 }
 
 #[cfg(test)]
@@ -45,4 +94,33 @@ mod test {
 
         assert!(jobs.is_ok());
     }
+
+    #[tokio::test]
+    async fn search() {
+        let (api, token) = crate::test::api().await;
+        let params = crate::param::Jobs {
+            job_type: Some(crate::data::JobType::VideoTranscoding),
+
+            ..Default::default()
+        };
+
+        let jobs = api
+            .jobs
+            .search(&token, crate::data::JobState::Completed, &params)
+            .await;
+
+        assert!(jobs.is_ok());
+    }
+
+    #[tokio::test]
+    async fn transcription() {
+        let (api, token) = crate::test::api().await;
+
+        let job = api
+            .jobs
+            .transcription(&token, "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(job.is_ok());
+    }
 }
@@ -12,15 +12,15 @@ impl History {
     /**
      * List watched videos history.
      */
-    pub async fn videos(
+    pub async fn videos<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::Video>> {
         let request = crate::Request {
             path: "/users/me/history/videos".into(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,9 +29,9 @@ impl History {
     /**
      * Clear video history.
      */
-    pub async fn clear(
+    pub async fn clear<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         before_date: &chrono::DateTime<chrono::offset::Utc>,
     ) -> crate::Result<()> {
         let params = crate::param::History {
@@ -41,7 +41,7 @@ impl History {
         let request = crate::Request {
             path: "/users/me/history/videos/remove".into(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -26,11 +26,11 @@ impl Me {
     /**
      * Get my user information.
      */
-    pub async fn info(&self, auth: &crate::data::Token) -> crate::Result<crate::data::User> {
+    pub async fn info<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<crate::data::User> {
         let request = crate::Request {
             path: "/users/me".into(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -39,15 +39,15 @@ impl Me {
     /**
      * Update my user information.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Me,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: "/users/me".into(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -56,15 +56,15 @@ impl Me {
     /**
      * Get video imports of my user.
      */
-    pub async fn imports(
+    pub async fn imports<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::Import>> {
         let request = crate::Request {
             path: "/users/me/videos/imports".into(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -73,11 +73,11 @@ impl Me {
     /**
      * Get my user used quota.
      */
-    pub async fn quota(&self, auth: &crate::data::Token) -> crate::Result<crate::data::Quota> {
+    pub async fn quota<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<crate::data::Quota> {
         let request = crate::Request {
             path: "/users/me/video-quota-used".into(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -86,15 +86,15 @@ impl Me {
     /**
      * Get rate of my user for a video.
      */
-    pub async fn video_rating(
+    pub async fn video_rating<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
     ) -> crate::Result<crate::data::Rating> {
         let request = crate::Request {
             path: format!("/users/me/videos/{id}/rating"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -103,15 +103,15 @@ impl Me {
     /**
      * Get videos of my user.
      */
-    pub async fn videos(
+    pub async fn videos<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::Video>> {
         let request = crate::Request {
             path: "/users/me/videos".into(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -120,15 +120,15 @@ impl Me {
     /**
      * Update my user avatar.
      */
-    pub async fn update_avatar(
+    pub async fn update_avatar<A: Into<crate::Auth>, F: crate::IntoFile>(
         &self,
-        auth: &crate::data::Token,
-        avatarfile: &str,
+        auth: A,
+        avatarfile: F,
     ) -> crate::Result<crate::data::Avatar> {
         let request = crate::Request {
             path: "/users/me/avatar/pick".into(),
             params: crate::Params::upload((), "avatarfile", avatarfile)?,
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -137,11 +137,11 @@ impl Me {
     /**
      * Delete my avatar.
      */
-    pub async fn delete_avatar(&self, auth: &crate::data::Token) -> crate::Result<()> {
+    pub async fn delete_avatar<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<()> {
         let request = crate::Request {
             path: "/users/me/avatar".into(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -152,15 +152,15 @@ impl Me {
     /**
      * List my abuses.
      */
-    pub async fn abuses(
+    pub async fn abuses<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Abuses,
     ) -> crate::Result<crate::Pager<crate::data::Abuse>> {
         let request = crate::Request {
             path: "/users/me/abuses".into(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -242,7 +242,7 @@ mod test {
     async fn update_avatar() {
         let (api, token) = crate::test::api().await;
 
-        let status = api.me.update_avatar(&token, "").await;
+        let status = api.me.update_avatar(&token, "fixtures/avatar.png").await;
 
         assert!(status.is_ok());
     }
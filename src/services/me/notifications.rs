@@ -12,15 +12,15 @@ impl Notifications {
     /**
      * List my notifications.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Notification,
     ) -> crate::Result<crate::Pager<crate::data::Notification>> {
         let request = crate::Request {
             path: "/users/me/notifications".into(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,13 +29,13 @@ impl Notifications {
     /**
      * Mark notifications as read by their id.
      */
-    pub async fn read(&self, auth: &crate::data::Token, ids: &[u32]) -> crate::Result<()> {
+    pub async fn read<A: Into<crate::Auth>>(&self, auth: A, ids: &[u32]) -> crate::Result<()> {
         let params = crate::param::Notifications { ids: ids.to_vec() };
 
         let request = crate::Request {
             path: "/users/me/notifications/read".into(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -46,11 +46,11 @@ impl Notifications {
     /**
      * Mark all my notification as read.
      */
-    pub async fn read_all(&self, auth: &crate::data::Token) -> crate::Result<()> {
+    pub async fn read_all<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<()> {
         let request = crate::Request {
             path: "/users/me/notifications/read-all".into(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -61,15 +61,15 @@ impl Notifications {
     /**
      * Update my notification settings.
      */
-    pub async fn settings(
+    pub async fn settings<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         settings: &crate::param::NotificationSettings,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: "/users/me/notification-settings".into(),
             params: crate::Params::Json(settings),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
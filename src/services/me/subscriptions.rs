@@ -12,15 +12,15 @@ impl Subscriptions {
     /**
      * Get my user subscriptions.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::Subscription>> {
         let request = crate::Request {
             path: "/users/me/subscriptions".into(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,7 +29,7 @@ impl Subscriptions {
     /**
      * Add subscription to my user.
      */
-    pub async fn add(&self, auth: &crate::data::Token, uri: &str) -> crate::Result<()> {
+    pub async fn add<A: Into<crate::Auth>>(&self, auth: A, uri: &str) -> crate::Result<()> {
         let params = crate::param::Subscription {
             uri: uri.to_string(),
         };
@@ -37,7 +37,7 @@ impl Subscriptions {
         let request = crate::Request {
             path: "/users/me/subscriptions".into(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -48,9 +48,9 @@ impl Subscriptions {
     /**
      * Get if subscriptions exist for my user.
      */
-    pub async fn exist<T: ToString>(
+    pub async fn exist<A: Into<crate::Auth>, T: ToString>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         uris: &[&T],
     ) -> crate::Result<std::collections::HashMap<String, bool>> {
         let params: Vec<_> = uris.iter().map(|x| ("uris", x.to_string())).collect();
@@ -58,7 +58,7 @@ impl Subscriptions {
         let request = crate::Request {
             path: "/users/me/subscriptions/exist".into(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -67,15 +67,15 @@ impl Subscriptions {
     /**
      * List videos of subscriptions of my user.
      */
-    pub async fn videos(
+    pub async fn videos<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Videos,
     ) -> crate::Result<crate::Pager<crate::data::Video>> {
         let request = crate::Request {
             path: "/users/me/subscriptions/videos".into(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -84,15 +84,15 @@ impl Subscriptions {
     /**
      * Get subscription of my user.
      */
-    pub async fn get(
+    pub async fn get<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         handle: &str,
     ) -> crate::Result<crate::data::Subscription> {
         let request = crate::Request {
             path: format!("/users/me/subscriptions/{handle}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -101,11 +101,11 @@ impl Subscriptions {
     /**
      * Delete subscription of my user.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, handle: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, handle: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/users/me/subscriptions/{handle}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -26,7 +26,7 @@ impl Playlists {
         let request = crate::Request {
             path: "/video-playlists".to_string(),
             params: crate::Params::Query(pagination),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -35,15 +35,15 @@ impl Playlists {
     /**
      * Create a video playlist.
      */
-    pub async fn create(
+    pub async fn create<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         playlist: &crate::param::Playlist,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: "/video-playlists".to_string(),
-            params: crate::Params::multipart(playlist)?,
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(playlist)?,
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -52,15 +52,15 @@ impl Playlists {
     /**
      * Get a video playlist.
      */
-    pub async fn get(
+    pub async fn get<A: Into<crate::Auth>>(
         &self,
-        auth: Option<&crate::data::Token>,
+        auth: A,
         id: &str,
     ) -> crate::Result<crate::data::Playlist> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}"),
             params: crate::Params::none(),
-            auth: auth.cloned(),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -69,16 +69,16 @@ impl Playlists {
     /**
      * Update a video playlist.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         params: &crate::param::PlaylistSetting,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}"),
-            params: crate::Params::multipart(params)?,
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(params)?,
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -87,11 +87,11 @@ impl Playlists {
     /**
      * Delete a video playlist.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -100,15 +100,15 @@ impl Playlists {
     /**
      * List videos of a playlist.
      */
-    pub async fn videos(
+    pub async fn videos<A: Into<crate::Auth>>(
         &self,
-        auth: Option<&crate::data::Token>,
+        auth: A,
         id: &str,
     ) -> crate::Result<crate::Pager<crate::data::Video>> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}/videos"),
             params: crate::Params::none(),
-            auth: auth.cloned(),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -117,16 +117,16 @@ impl Playlists {
     /**
      * Add a video in a playlist.
      */
-    pub async fn add_video(
+    pub async fn add_video<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         element: &crate::param::PlaylistElement,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}/videos"),
             params: crate::Params::Json(element),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -135,16 +135,16 @@ impl Playlists {
     /**
      * Reorder a playlist.
      */
-    pub async fn reorder(
+    pub async fn reorder<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         reorder: &crate::param::Reorder,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}/videos/reorder"),
             params: crate::Params::Json(reorder),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -155,16 +155,16 @@ impl Playlists {
     /**
      * Update a playlist element.
      */
-    pub async fn update_video(
+    pub async fn update_video<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         element: &crate::param::PlaylistElement,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}/videos/{}", element.video_id),
             params: crate::Params::Json(element),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -173,16 +173,16 @@ impl Playlists {
     /**
      * Delete an element from a playlist.
      */
-    pub async fn delete_video(
+    pub async fn delete_video<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         element_id: &str,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/video-playlists/{id}/videos/{element_id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -191,19 +191,20 @@ impl Playlists {
     /**
      * Check video exists in my playlists.
      */
-    pub async fn exists<T: ToString>(
+    pub async fn exists<A: Into<crate::Auth>, T: ToString>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         element_id: &[T],
     ) -> crate::Result<()> {
-        let params = crate::param::Elements {
-            video_ids: element_id.iter().map(|x| x.to_string()).collect(),
-        };
+        let params: Vec<_> = element_id
+            .iter()
+            .map(|x| ("videoIds", x.to_string()))
+            .collect();
 
         let request = crate::Request {
             path: "/video-playlists/videos-exist".to_string(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
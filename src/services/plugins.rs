@@ -12,15 +12,15 @@ impl Plugins {
     /**
      * List plugins.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Abuses,
     ) -> crate::Result<crate::Pager<crate::data::Plugin>> {
         let request = crate::Request {
             path: "/plugins".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,15 +29,15 @@ impl Plugins {
     /**
      * List available plugins.
      */
-    pub async fn available(
+    pub async fn available<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Abuses,
     ) -> crate::Result<crate::Pager<crate::data::AvailablePlugin>> {
         let request = crate::Request {
             path: "/plugins/available".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -46,28 +46,36 @@ impl Plugins {
     /**
      * Install a plugin.
      */
-    pub async fn install(&self, auth: &crate::data::Token, npm_name: &str) -> crate::Result<()> {
+    pub async fn install<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        npm_name: &str,
+    ) -> crate::Result<()> {
         self.action("install", auth, npm_name).await
     }
 
     /**
      * Update a plugin.
      */
-    pub async fn update(&self, auth: &crate::data::Token, npm_name: &str) -> crate::Result<()> {
+    pub async fn update<A: Into<crate::Auth>>(&self, auth: A, npm_name: &str) -> crate::Result<()> {
         self.action("update", auth, npm_name).await
     }
 
     /**
      * Uninstall a plugin.
      */
-    pub async fn uninstall(&self, auth: &crate::data::Token, npm_name: &str) -> crate::Result<()> {
+    pub async fn uninstall<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        npm_name: &str,
+    ) -> crate::Result<()> {
         self.action("uninstall", auth, npm_name).await
     }
 
-    async fn action(
+    async fn action<A: Into<crate::Auth>>(
         &self,
         action: &str,
-        auth: &crate::data::Token,
+        auth: A,
         npm_name: &str,
     ) -> crate::Result<()> {
         let params = crate::param::Plugin {
@@ -77,7 +85,7 @@ impl Plugins {
         let request = crate::Request {
             path: format!("/plugins/{action}"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -88,15 +96,15 @@ impl Plugins {
     /**
      * Get a plugin.
      */
-    pub async fn get(
+    pub async fn get<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         npm_name: &str,
     ) -> crate::Result<crate::data::Plugin> {
         let request = crate::Request {
             path: format!("/plugins/{npm_name}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -105,16 +113,16 @@ impl Plugins {
     /**
      * Set a plugin's settings.
      */
-    pub async fn settings(
+    pub async fn settings<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         npm_name: &str,
         settings: crate::param::PluginSettings,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/plugins/{npm_name}/settings"),
             params: crate::Params::Json(settings),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -137,15 +145,15 @@ impl Plugins {
     /**
      * Get a plugin's registered settings.
      */
-    pub async fn registered_settings(
+    pub async fn registered_settings<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         npm_name: &str,
     ) -> crate::Result<crate::data::RegisteredSettings> {
         let request = crate::Request {
             path: format!("/plugins/{npm_name}/registered-settings"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -0,0 +1,459 @@
+use futures_util::StreamExt;
+use tokio::io::AsyncWriteExt;
+
+/**
+ * Remote jobs, processed by runners.
+ *
+ * A runner requests the available jobs, accepts one, downloads its inputs,
+ * reports its progress and finally sends the result or an error.
+ * Administrators can list, cancel and delete jobs.
+ */
+pub struct Jobs {
+    config: crate::Config,
+}
+
+impl Jobs {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * List jobs.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        params: &crate::param::RunnerJobs,
+    ) -> crate::Result<crate::Pager<crate::data::RunnerJob>> {
+        let request = crate::Request {
+            path: "/runners/jobs".to_string(),
+            params: crate::Params::Query(crate::fields(params)?),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Cancel a job, and its children.
+     */
+    pub async fn cancel<A: Into<crate::Auth>>(&self, auth: A, uuid: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/runners/jobs/{uuid}/cancel"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Delete a job, and its children.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, uuid: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/runners/jobs/{uuid}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+
+    /**
+     * List jobs waiting for a runner, restricted to `types` if given.
+     */
+    pub async fn request(
+        &self,
+        runner_token: &str,
+        types: Option<&[crate::data::RunnerJobType]>,
+    ) -> crate::Result<Vec<crate::data::AvailableJob>> {
+        let params = crate::param::RequestJobs {
+            runner_token: runner_token.to_string(),
+            job_types: types.map(<[_]>::to_vec),
+        };
+
+        let request = crate::Request {
+            path: "/runners/jobs/request".to_string(),
+            params: crate::Params::Json(params),
+            auth: crate::Auth::Anonymous,
+        };
+
+        let jobs: crate::data::AvailableJobs = crate::Api::post(&self.config, request).await?;
+
+        Ok(jobs.available_jobs)
+    }
+
+    /**
+     * Accept a job, the job can't be accepted by another runner anymore.
+     */
+    pub async fn accept(
+        &self,
+        runner_token: &str,
+        uuid: &str,
+    ) -> crate::Result<crate::data::AcceptedRunnerJob> {
+        let params = crate::param::Runner {
+            runner_token: runner_token.to_string(),
+        };
+
+        let request = crate::Request {
+            path: format!("/runners/jobs/{uuid}/accept"),
+            params: crate::Params::Json(params),
+            auth: crate::Auth::Anonymous,
+        };
+
+        let accepted: crate::data::Accepted = crate::Api::post(&self.config, request).await?;
+
+        Ok(accepted.job)
+    }
+
+    /**
+     * Give the job back, so another runner can process it.
+     */
+    pub async fn abort(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        reason: &str,
+    ) -> crate::Result<()> {
+        let params = crate::param::JobAbort {
+            token: token(runner_token, job),
+            reason: reason.to_string(),
+        };
+
+        self.post(job, "abort", crate::Params::Json(params)).await
+    }
+
+    /**
+     * Report the progress of a job, in percent.
+     *
+     * The server aborts jobs without update for a while, this should be
+     * called regularly even if the progress is unknown.
+     */
+    pub async fn update(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        progress: Option<u32>,
+    ) -> crate::Result<()> {
+        let params = crate::param::JobUpdate {
+            token: token(runner_token, job),
+            progress,
+            payload: None,
+        };
+
+        self.post(job, "update", crate::Params::Json(params)).await
+    }
+
+    /**
+     * Send a new segment of a live transcoding job, or notify its deletion.
+     */
+    pub async fn update_live(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        chunk: crate::param::LiveChunk,
+    ) -> crate::Result<()> {
+        use crate::param::{LiveChunk, LiveChunkFields, LiveChunkFiles};
+
+        let (payload, files) = match chunk {
+            LiveChunk::Add(files) => {
+                let LiveChunkFiles {
+                    video_chunk,
+                    resolution_playlist,
+                    master_playlist,
+                } = *files;
+
+                let payload = LiveChunkFields {
+                    r#type: "add-chunk",
+                    video_chunk_filename: video_chunk.name().to_string(),
+                    resolution_playlist_filename: Some(resolution_playlist.name().to_string()),
+                };
+
+                let mut files = vec![
+                    ("payload[videoChunkFile]", video_chunk),
+                    ("payload[resolutionPlaylistFile]", resolution_playlist),
+                ];
+                files.extend(master_playlist.map(|x| ("payload[masterPlaylistFile]", x)));
+
+                (payload, files)
+            }
+            LiveChunk::Remove {
+                video_chunk_filename,
+            } => {
+                let payload = LiveChunkFields {
+                    r#type: "remove-chunk",
+                    video_chunk_filename,
+                    resolution_playlist_filename: None,
+                };
+
+                (payload, Vec::new())
+            }
+        };
+
+        let params = crate::param::JobUpdate {
+            token: token(runner_token, job),
+            progress: None,
+            payload: Some(payload),
+        };
+
+        let mut params = crate::Params::multipart(params)?;
+
+        for (name, file) in files {
+            params = params.file(name, file)?;
+        }
+
+        self.post(job, "update", params).await
+    }
+
+    /**
+     * Report a failure, the server retries the job on another runner a few
+     * times before giving up.
+     */
+    pub async fn error(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        message: &str,
+    ) -> crate::Result<()> {
+        let params = crate::param::JobError {
+            token: token(runner_token, job),
+            message: message.to_string(),
+        };
+
+        self.post(job, "error", crate::Params::Json(params)).await
+    }
+
+    /**
+     * Send the result of a job.
+     */
+    pub async fn success(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        result: crate::param::RunnerJobSuccess,
+    ) -> crate::Result<()> {
+        let mut params = crate::Params::multipart(token(runner_token, job))?;
+
+        for (name, file) in result.into_files() {
+            params = params.file(name, file)?;
+        }
+
+        self.post(job, "success", params).await
+    }
+
+    /**
+     * Download an input file of a job, like `VodInput::video_file_url`, to
+     * `path`.
+     *
+     * Returns the number of bytes written.
+     */
+    pub async fn download<P: AsRef<std::path::Path>>(
+        &self,
+        runner_token: &str,
+        job: &crate::data::AcceptedRunnerJob,
+        url: &str,
+        path: P,
+    ) -> crate::Result<u64> {
+        let response = self
+            .config
+            .client
+            .post(url)
+            .json(&token(runner_token, job))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(crate::PeertubeError::from_response(response).await?.into());
+        }
+
+        let mut file = tokio::fs::File::create(path).await?;
+        let mut stream = response.bytes_stream();
+        let mut size = 0;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+
+            file.write_all(&chunk).await?;
+            size += chunk.len() as u64;
+        }
+
+        file.flush().await?;
+
+        Ok(size)
+    }
+
+    async fn post<P: serde::Serialize>(
+        &self,
+        job: &crate::data::AcceptedRunnerJob,
+        action: &str,
+        params: crate::Params<P>,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/runners/jobs/{}/{action}", job.uuid),
+            params,
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+}
+
+fn token(runner_token: &str, job: &crate::data::AcceptedRunnerJob) -> crate::param::JobToken {
+    crate::param::JobToken {
+        runner_token: runner_token.to_string(),
+        job_token: job.job_token.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    const RUNNER_TOKEN: &str = "ptrt-7d3b5a95-7a42-4c1b-9d4a-8c3ee9f7e1a5";
+
+    async fn accept() -> (crate::test::Api, crate::data::AcceptedRunnerJob) {
+        let (api, _) = crate::test::api().await;
+
+        let jobs = api.runners.jobs.request(RUNNER_TOKEN, None).await.unwrap();
+        let job = api
+            .runners
+            .jobs
+            .accept(RUNNER_TOKEN, &jobs[0].uuid)
+            .await
+            .unwrap();
+
+        (api, job)
+    }
+
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let params = crate::param::RunnerJobs {
+            state_one_of: vec![
+                crate::data::RunnerJobState::Pending,
+                crate::data::RunnerJobState::Processing,
+            ],
+            ..Default::default()
+        };
+        let jobs = api.runners.jobs.all(&token, &params).await;
+
+        assert!(jobs.is_ok());
+    }
+
+    #[tokio::test]
+    async fn cancel() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .runners
+            .jobs
+            .cancel(&token, "0d5c3e09-0c7b-4a9c-8d0e-5f6a7b8c9d0e")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .runners
+            .jobs
+            .delete(&token, "0d5c3e09-0c7b-4a9c-8d0e-5f6a7b8c9d0e")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let (api, _) = crate::test::api().await;
+
+        let jobs = api
+            .runners
+            .jobs
+            .request(
+                RUNNER_TOKEN,
+                Some(&[crate::data::RunnerJobType::VodWebVideoTranscoding]),
+            )
+            .await;
+
+        assert!(jobs.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update() {
+        let (api, job) = accept().await;
+
+        let status = api.runners.jobs.update(RUNNER_TOKEN, &job, Some(50)).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update_live() {
+        let (api, job) = accept().await;
+
+        let chunk = crate::param::LiveChunk::add(
+            crate::File::bytes("0-000001.ts", vec![0; 188]),
+            crate::File::bytes("0.m3u8", "#EXTM3U\n"),
+        );
+        let status = api
+            .runners
+            .jobs
+            .update_live(RUNNER_TOKEN, &job, chunk)
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn abort() {
+        let (api, job) = accept().await;
+
+        let status = api.runners.jobs.abort(RUNNER_TOKEN, &job, "shutdown").await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn error() {
+        let (api, job) = accept().await;
+
+        let status = api.runners.jobs.error(RUNNER_TOKEN, &job, "failed").await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn download_and_success() {
+        let (api, job) = accept().await;
+
+        let crate::data::RunnerJobPayload::VodWebVideoTranscoding(payload) = &job.payload else {
+            panic!("unexpected job type");
+        };
+
+        let path = std::env::temp_dir().join(format!("{}.mp4", job.uuid));
+        let size = api
+            .runners
+            .jobs
+            .download(RUNNER_TOKEN, &job, &payload.input.video_file_url, &path)
+            .await;
+        assert!(size.is_ok());
+
+        let result = crate::param::RunnerJobSuccess::VodWebVideoTranscoding {
+            video_file: crate::File::path(&path).unwrap(),
+        };
+        let status = api.runners.jobs.success(RUNNER_TOKEN, &job, result).await;
+        std::fs::remove_file(path).ok();
+
+        assert!(status.is_ok());
+    }
+}
@@ -1,25 +1,35 @@
+mod jobs;
+mod registration_tokens;
+
+pub use jobs::Jobs;
+pub use registration_tokens::RegistrationTokens;
+
 pub struct Runners {
     config: crate::Config,
+    pub jobs: Jobs,
+    pub registration_tokens: RegistrationTokens,
 }
 
 impl Runners {
     pub(crate) fn new(config: &crate::Config) -> Self {
         Self {
             config: config.clone(),
+            jobs: Jobs::new(config),
+            registration_tokens: RegistrationTokens::new(config),
         }
     }
 
     /**
      * List runners
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
     ) -> crate::Result<crate::Pager<crate::data::Runner>> {
         let request = crate::Request {
             path: "/runners".to_string(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -28,16 +38,16 @@ impl Runners {
     /**
      * Delete a runner.
      */
-    pub async fn delete(
+    pub async fn delete<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: u32,
         runner: &crate::param::Runner,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/runners/{id}"),
             params: crate::Params::Json(runner),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -53,7 +63,7 @@ impl Runners {
         let request = crate::Request {
             path: "/runners/register".to_string(),
             params: crate::Params::Json(runner),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::post(&self.config, request).await
@@ -66,7 +76,7 @@ impl Runners {
         let request = crate::Request {
             path: "/runners/unregister".to_string(),
             params: crate::Params::Json(runner),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
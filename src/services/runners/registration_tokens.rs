@@ -0,0 +1,91 @@
+pub struct RegistrationTokens {
+    config: crate::Config,
+}
+
+impl RegistrationTokens {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * List registration tokens.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        params: &crate::param::Pagination,
+    ) -> crate::Result<crate::Pager<crate::data::RunnerRegistrationToken>> {
+        let request = crate::Request {
+            path: "/runners/registration-tokens".to_string(),
+            params: crate::Params::Query(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Generate a registration token, to give to new runners.
+     */
+    pub async fn generate<A: Into<crate::Auth>>(&self, auth: A) -> crate::Result<()> {
+        let request = crate::Request {
+            path: "/runners/registration-tokens/generate".to_string(),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Delete a registration token, runners registered with it are deleted
+     * too.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: u32) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/runners/registration-tokens/{id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let tokens = api
+            .runners
+            .registration_tokens
+            .all(&token, &crate::param::Pagination::default())
+            .await;
+
+        assert!(tokens.is_ok());
+    }
+
+    #[tokio::test]
+    async fn generate() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.runners.registration_tokens.generate(&token).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.runners.registration_tokens.delete(&token, 1).await;
+
+        assert!(status.is_ok());
+    }
+}
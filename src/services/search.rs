@@ -19,7 +19,7 @@ impl Search {
         let request = crate::Request {
             path: "/search/videos".to_string(),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -35,7 +35,7 @@ impl Search {
         let request = crate::Request {
             path: "/search/video-channels".to_string(),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -12,15 +12,15 @@ impl Accounts {
     /**
      * List account blocks.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::BlockedAccount>> {
         let request = crate::Request {
             path: "/server/blocklist/accounts".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,7 +29,7 @@ impl Accounts {
     /**
      * Block a account.
      */
-    pub async fn add(&self, auth: &crate::data::Token, handle: &str) -> crate::Result<()> {
+    pub async fn add<A: Into<crate::Auth>>(&self, auth: A, handle: &str) -> crate::Result<()> {
         let params = crate::data::AccountName {
             account_name: handle.to_string(),
         };
@@ -37,7 +37,7 @@ impl Accounts {
         let request = crate::Request {
             path: "/server/blocklist/accounts".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -48,11 +48,11 @@ impl Accounts {
     /**
      * Unblock a account by its handle.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, handle: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, handle: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/server/blocklist/accounts/{handle}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
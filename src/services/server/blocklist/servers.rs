@@ -12,15 +12,15 @@ impl Servers {
     /**
      * List server blocks.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::Pagination,
     ) -> crate::Result<crate::Pager<crate::data::BlockedServer>> {
         let request = crate::Request {
             path: "/server/blocklist/servers".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,7 +29,7 @@ impl Servers {
     /**
      * Block a server.
      */
-    pub async fn add(&self, auth: &crate::data::Token, host: &str) -> crate::Result<()> {
+    pub async fn add<A: Into<crate::Auth>>(&self, auth: A, host: &str) -> crate::Result<()> {
         let params = crate::data::Server {
             host: host.to_string(),
         };
@@ -37,7 +37,7 @@ impl Servers {
         let request = crate::Request {
             path: "/server/blocklist/servers".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -48,11 +48,11 @@ impl Servers {
     /**
      * Unblock a server by its domain.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, host: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, host: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/server/blocklist/servers/{host}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -22,11 +22,11 @@ impl Server {
     /**
      * Unfollow a server.
      */
-    pub async fn unfollow(&self, auth: &crate::data::Token, host: &str) -> crate::Result<()> {
+    pub async fn unfollow<A: Into<crate::Auth>>(&self, auth: A, host: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/server/following/{host}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -42,7 +42,7 @@ impl Server {
         let request = crate::Request {
             path: "/server/following".to_string(),
             params: crate::Params::Query(pagination),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -58,7 +58,7 @@ impl Server {
         let request = crate::Request {
             path: "/server/followers".to_string(),
             params: crate::Params::Query(pagination),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -67,7 +67,7 @@ impl Server {
     /**
      * Follow a server.
      */
-    pub async fn follow(&self, auth: &crate::data::Token, host: &str) -> crate::Result<()> {
+    pub async fn follow<A: Into<crate::Auth>>(&self, auth: A, host: &str) -> crate::Result<()> {
         let params = crate::param::Following {
             host: host.to_string(),
         };
@@ -75,7 +75,7 @@ impl Server {
         let request = crate::Request {
             path: "/server/following".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -12,15 +12,15 @@ impl Redundancy {
     /**
      * List videos being mirrored.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Redundancies,
     ) -> crate::Result<crate::Pager<crate::data::Redundancy>> {
         let request = crate::Request {
             path: "/server/redundancy/videos".to_string(),
             params: crate::Params::Query(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -29,13 +29,13 @@ impl Redundancy {
     /**
      * Mirror a video.
      */
-    pub async fn add(&self, auth: &crate::data::Token, video_id: u32) -> crate::Result<()> {
+    pub async fn add<A: Into<crate::Auth>>(&self, auth: A, video_id: u32) -> crate::Result<()> {
         let params = crate::param::Redundancy { video_id };
 
         let request = crate::Request {
             path: "/server/redundancy/videos".to_string(),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -46,11 +46,11 @@ impl Redundancy {
     /**
      * Delete a mirror done on a video.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/server/redundancy/videos/{id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -59,9 +59,9 @@ impl Redundancy {
     /**
      * Update a server redundancy policy.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         host: &str,
         allowed: bool,
     ) -> crate::Result<()> {
@@ -72,7 +72,7 @@ impl Redundancy {
         let request = crate::Request {
             path: format!("/server/redundancy/{host}"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -0,0 +1,595 @@
+mod registrations;
+
+pub use registrations::Registrations;
+
+use futures_util::TryStreamExt;
+
+pub struct Users {
+    config: crate::Config,
+    pub registrations: Registrations,
+}
+
+impl Users {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+            registrations: Registrations::new(config),
+        }
+    }
+
+    /**
+     * Create a user.
+     */
+    pub async fn create<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        user: &crate::param::NewUser,
+    ) -> crate::Result<crate::data::NewUser> {
+        let request = crate::Request {
+            path: "/users".to_string(),
+            params: crate::Params::Json(user),
+            auth: auth.into(),
+        };
+
+        let data = crate::Api::post(&self.config, request).await?;
+
+        let crate::data::Data::NewUser(user) = data;
+
+        Ok(user)
+    }
+
+    /**
+     * List users.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        params: &crate::param::Users,
+    ) -> crate::Result<crate::Pager<crate::data::User>> {
+        let request = crate::Request {
+            path: "/users".to_string(),
+            params: crate::Params::Query(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Delete a user.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: u32) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+
+    /**
+     * Get a user.
+     */
+    pub async fn get<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+    ) -> crate::Result<crate::data::User> {
+        let request = crate::Request {
+            path: format!("/users/{id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Update a user.
+     */
+    pub async fn update<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        params: &crate::param::User,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}"),
+            params: crate::Params::Json(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::put(&self.config, request).await
+    }
+
+    /**
+     * Block a user, they can't log in anymore.
+     */
+    pub async fn block<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        reason: Option<&str>,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/block"),
+            params: crate::Params::Json(crate::param::Block {
+                reason: reason.map(ToString::to_string),
+            }),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Unblock a user.
+     */
+    pub async fn unblock<A: Into<crate::Auth>>(&self, auth: A, id: u32) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/unblock"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Apply `action` to every user matching `params`, the pagination of
+     * `params` is ignored.
+     *
+     * Matching users are all listed before the first action, then the action
+     * is applied to each user in turn: a failure doesn't stop the other
+     * actions and is reported with its user.
+     *
+     * The authenticated user and the `root` account are never affected, they
+     * are left out of the outcomes even if they match `params`.
+     */
+    pub async fn bulk<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        params: &crate::param::Users,
+        action: &crate::param::UserAction,
+    ) -> crate::Result<Vec<(crate::data::User, crate::Result<()>)>> {
+        let auth: &crate::Auth = &auth.into();
+
+        let users = crate::Paginator::new(|pagination| async move {
+            let params = crate::param::Users {
+                blocked: params.blocked,
+                search: params.search.clone(),
+                pagination: crate::param::Pagination {
+                    sort: params.pagination.sort.clone(),
+
+                    ..pagination
+                },
+            };
+
+            self.all(auth, &params).await
+        })
+        .into_stream()
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let request = crate::Request {
+            path: "/users/me".to_string(),
+            params: crate::Params::none(),
+            auth: auth.clone(),
+        };
+        let me: crate::data::User = crate::Api::get(&self.config, request).await?;
+
+        let users = users
+            .into_iter()
+            .filter(|x| x.id != me.id && x.username != "root")
+            .collect::<Vec<_>>();
+        let mut outcomes = Vec::with_capacity(users.len());
+
+        for user in users {
+            let result = match action {
+                crate::param::UserAction::Block { reason } => {
+                    self.block(auth, user.id, reason.as_deref()).await
+                }
+                crate::param::UserAction::Unblock => self.unblock(auth, user.id).await,
+                crate::param::UserAction::Delete => self.delete(auth, user.id).await,
+                crate::param::UserAction::Quota {
+                    video_quota,
+                    video_quota_daily,
+                } => {
+                    let params = crate::param::User {
+                        id: user.id,
+                        video_quota: *video_quota,
+                        video_quota_daily: *video_quota_daily,
+
+                        ..Default::default()
+                    };
+
+                    self.update(auth, user.id, &params).await
+                }
+            };
+
+            outcomes.push((user, result));
+        }
+
+        Ok(outcomes)
+    }
+
+    /**
+     * Register a user.
+     */
+    pub async fn register(&self, params: &crate::param::Register) -> crate::Result<()> {
+        let request = crate::Request {
+            path: "/users/register".to_string(),
+            params: crate::Params::Json(params),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Start enabling two-factor authentication, it is enabled by
+     * `confirm_two_factor`.
+     */
+    pub async fn request_two_factor<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        current_password: &str,
+    ) -> crate::Result<crate::data::TwoFactorRequest> {
+        let request = crate::Request {
+            path: format!("/users/{id}/two-factor/request"),
+            params: crate::Params::Json(crate::param::CurrentPassword {
+                current_password: current_password.to_string(),
+            }),
+            auth: auth.into(),
+        };
+
+        let data: crate::data::OtpRequest = crate::Api::post(&self.config, request).await?;
+
+        Ok(data.otp_request)
+    }
+
+    /**
+     * Enable two-factor authentication with a code generated from the
+     * secret of `request_two_factor`.
+     */
+    pub async fn confirm_two_factor<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        request_token: &str,
+        otp: &str,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/two-factor/confirm-request"),
+            params: crate::Params::Json(crate::param::ConfirmTwoFactor {
+                request_token: request_token.to_string(),
+                otp_token: otp.to_string(),
+            }),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Disable two-factor authentication.
+     */
+    pub async fn disable_two_factor<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        current_password: &str,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/two-factor/disable"),
+            params: crate::Params::Json(crate::param::CurrentPassword {
+                current_password: current_password.to_string(),
+            }),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Send again the email to verify the address of a user.
+     *
+     * The instance answers the same way if no user has this address.
+     */
+    pub async fn ask_verify_email(&self, email: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: "/users/ask-send-verify-email".to_string(),
+            params: crate::Params::Json(crate::param::Email {
+                email: email.to_string(),
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Verify the email of a user with the verification string sent by email.
+     *
+     * `is_pending_email` is set to verify a new address after an email
+     * change.
+     */
+    pub async fn verify_email(
+        &self,
+        id: u32,
+        verification_string: &str,
+        is_pending_email: bool,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/verify-email"),
+            params: crate::Params::Json(crate::param::VerifyEmail {
+                verification_string: verification_string.to_string(),
+                is_pending_email,
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Send a password reset email.
+     *
+     * The instance answers the same way if no user has this address.
+     */
+    pub async fn ask_reset_password(&self, email: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: "/users/ask-reset-password".to_string(),
+            params: crate::Params::Json(crate::param::Email {
+                email: email.to_string(),
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Set a new password with the verification string sent by
+     * `ask_reset_password`.
+     */
+    pub async fn reset_password(
+        &self,
+        id: u32,
+        verification_string: &str,
+        password: &str,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/{id}/reset-password"),
+            params: crate::Params::Json(crate::param::ResetPassword {
+                verification_string: verification_string.to_string(),
+                password: password.to_string(),
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn create() {
+        let (api, token) = crate::test::api().await;
+        let param = crate::param::NewUser {
+            email: "test@example.org".to_string(),
+            password: "123456".to_string(),
+            username: "test".to_string(),
+
+            ..Default::default()
+        };
+        let user = api.users.create(&token, &param).await;
+
+        assert!(user.is_ok());
+    }
+
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let users = api.users.all(&token, &crate::param::Users::default()).await;
+
+        assert!(users.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.users.delete(&token, 999).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let (api, token) = crate::test::api().await;
+
+        let user = api.users.get(&token, 1).await;
+
+        assert!(user.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update() {
+        let (api, token) = crate::test::api().await;
+        let param = crate::param::User {
+            id: 1,
+            video_quota_daily: Some(100),
+
+            ..Default::default()
+        };
+        let user = api.users.update(&token, 1, &param).await;
+
+        assert!(user.is_ok());
+    }
+
+    #[tokio::test]
+    async fn register() {
+        let (api, _) = crate::test::api().await;
+        let param = crate::param::Register {
+            email: "test@example.org".to_string(),
+            password: "123456".to_string(),
+            username: "test".to_string(),
+
+            ..Default::default()
+        };
+        let user = api.users.register(&param).await;
+
+        assert!(user.is_ok());
+    }
+
+    #[tokio::test]
+    async fn block() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.users.block(&token, 999, Some("spam")).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unblock() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.users.unblock(&token, 999).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn bulk() {
+        let (server, api, token) = crate::test::server().await;
+
+        let user = |id: u32, username: &str| {
+            let mut user = crate::testing::fixtures::user();
+            user["id"] = id.into();
+            user["username"] = username.into();
+
+            user
+        };
+        let me = user(2, "admin");
+        server.mock(
+            "GET",
+            "/api/v1/users/me",
+            crate::testing::Response::json(me.clone()),
+        );
+        server.mock(
+            "GET",
+            "/api/v1/users",
+            crate::testing::Response::json(crate::testing::fixtures::pager(vec![
+                user(1, "root"),
+                me,
+                user(3, "spammer"),
+                user(4, "other-spammer"),
+            ])),
+        );
+        server.mock(
+            "POST",
+            "/api/v1/users/4/block",
+            crate::testing::Response::empty().status(403),
+        );
+
+        let params = crate::param::Users {
+            search: Some("spammer".to_string()),
+
+            ..Default::default()
+        };
+        let action = crate::param::UserAction::Block {
+            reason: Some("spam".to_string()),
+        };
+        let outcomes = api.users.bulk(&token, &params, &action).await.unwrap();
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].0.id, 3);
+        assert!(outcomes[0].1.is_ok());
+        assert!(outcomes[1].1.as_ref().unwrap_err().is_forbidden());
+    }
+
+    #[tokio::test]
+    async fn ask_verify_email() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api.users.ask_verify_email("test@example.org").await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_email() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api.users.verify_email(2, "verification", false).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ask_reset_password() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api.users.ask_reset_password("test@example.org").await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reset_password() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api
+            .users
+            .reset_password(2, "verification", "new password")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn two_factor() {
+        let (api, token) = crate::test::api().await;
+        let password = crate::test::password();
+
+        let request = api
+            .users
+            .request_two_factor(&token, 1, &password)
+            .await
+            .unwrap();
+        let otp = crate::totp::code(&request.secret).unwrap();
+
+        let status = api
+            .users
+            .confirm_two_factor(&token, 1, &request.request_token, &otp)
+            .await;
+        assert!(status.is_ok());
+
+        let status = api.users.disable_two_factor(&token, 1, &password).await;
+        assert!(status.is_ok());
+    }
+}
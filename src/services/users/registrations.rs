@@ -0,0 +1,234 @@
+pub struct Registrations {
+    config: crate::Config,
+}
+
+impl Registrations {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * Ask to register on an instance requiring moderator approval, see
+     * `Users::register` for other instances.
+     */
+    pub async fn request(
+        &self,
+        params: &crate::param::RegistrationRequest,
+    ) -> crate::Result<crate::data::Registration> {
+        let request = crate::Request {
+            path: "/users/registrations/request".to_string(),
+            params: crate::Params::Json(params),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post(&self.config, request).await
+    }
+
+    /**
+     * List registration requests.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        params: &crate::param::Registrations,
+    ) -> crate::Result<crate::Pager<crate::data::Registration>> {
+        let request = crate::Request {
+            path: "/users/registrations".to_string(),
+            params: crate::Params::Query(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Accept a registration request, the user is created.
+     */
+    pub async fn accept<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        params: &crate::param::Moderation,
+    ) -> crate::Result<()> {
+        self.moderate(auth, id, "accept", params).await
+    }
+
+    /**
+     * Reject a registration request.
+     */
+    pub async fn reject<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        params: &crate::param::Moderation,
+    ) -> crate::Result<()> {
+        self.moderate(auth, id, "reject", params).await
+    }
+
+    /**
+     * Delete a registration request, the user created by an accepted request
+     * is kept.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: u32) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/registrations/{id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+
+    /**
+     * Send again the email to verify the address of a registration request.
+     */
+    pub async fn ask_verify_email(&self, email: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: "/users/registrations/ask-send-verify-email".to_string(),
+            params: crate::Params::Json(crate::param::Email {
+                email: email.to_string(),
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Verify the email of a registration request with the verification
+     * string sent by email.
+     */
+    pub async fn verify_email(&self, id: u32, verification_string: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/registrations/{id}/verify-email"),
+            params: crate::Params::Json(crate::param::VerifyEmail {
+                verification_string: verification_string.to_string(),
+                is_pending_email: false,
+            }),
+            auth: crate::Auth::Anonymous,
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    async fn moderate<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: u32,
+        action: &str,
+        params: &crate::param::Moderation,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/users/registrations/{id}/{action}"),
+            params: crate::Params::Json(params),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn request() {
+        let (api, _) = crate::test::api().await;
+        let params = crate::param::RegistrationRequest {
+            register: crate::param::Register {
+                email: "test@example.org".to_string(),
+                password: "123456".to_string(),
+                username: "test".to_string(),
+
+                ..Default::default()
+            },
+            registration_reason: "I want to upload videos".to_string(),
+        };
+
+        let registration = api.users.registrations.request(&params).await;
+
+        assert!(registration.is_ok());
+    }
+
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let registrations = api
+            .users
+            .registrations
+            .all(&token, &crate::param::Registrations::default())
+            .await;
+
+        assert!(registrations.is_ok());
+    }
+
+    #[tokio::test]
+    async fn accept() {
+        let (api, token) = crate::test::api().await;
+        let params = crate::param::Moderation {
+            moderation_response: "Welcome".to_string(),
+
+            ..Default::default()
+        };
+
+        let status = api.users.registrations.accept(&token, 1, &params).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject() {
+        let (api, token) = crate::test::api().await;
+        let params = crate::param::Moderation {
+            moderation_response: "Sorry".to_string(),
+            prevent_email_delivery: Some(true),
+        };
+
+        let status = api.users.registrations.reject(&token, 1, &params).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api.users.registrations.delete(&token, 1).await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ask_verify_email() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api
+            .users
+            .registrations
+            .ask_verify_email("test@example.org")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_email() {
+        let (api, _) = crate::test::api().await;
+
+        let status = api
+            .users
+            .registrations
+            .verify_email(1, "verification")
+            .await;
+
+        assert!(status.is_ok());
+    }
+}
@@ -16,38 +16,175 @@ impl Captions {
         crate::Api::get(&self.config, format!("/videos/{video_id}/captions").into()).await
     }
 
+    /**
+     * List captions of a password protected video.
+     */
+    pub async fn all_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        password: &str,
+    ) -> crate::Result<crate::Pager<crate::data::Caption>> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/captions"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::request_with_headers(
+            reqwest::Method::GET,
+            &self.config,
+            request,
+            super::password_header(password)?,
+        )
+        .await
+    }
+
     /**
      * Add or replace a video caption.
      */
-    pub async fn add(
+    pub async fn add<A: Into<crate::Auth>, F: crate::IntoFile>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
         language: &str,
-        captionfile: &str,
+        captionfile: F,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{video_id}/captions/{language}"),
             params: crate::Params::upload((), "captionfile", captionfile)?,
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
     }
 
+    /**
+     * Ask the instance to transcribe the audio of a video, the caption is
+     * added when the transcription job ends, see `Jobs::transcription`.
+     *
+     * `force_transcription` replaces an existing caption.
+     */
+    pub async fn generate<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        force_transcription: bool,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/captions/generate"),
+            params: crate::Params::Json(crate::param::GenerateCaption {
+                force_transcription,
+            }),
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Add or replace a video caption from cues, sent as a WebVTT file.
+     */
+    pub async fn add_cues<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        language: &str,
+        cues: &[crate::captions::Cue],
+    ) -> crate::Result<()> {
+        let format = crate::captions::Format::WebVtt;
+        let file = crate::File::bytes(
+            &format!("{language}.{}", format.extension()),
+            crate::captions::format(cues, format),
+        );
+
+        self.add(auth, video_id, language, file).await
+    }
+
+    /**
+     * Download the file of a caption.
+     */
+    pub async fn download(&self, caption: &crate::data::Caption) -> crate::Result<String> {
+        self.fetch(crate::Auth::Anonymous, caption, Default::default())
+            .await
+    }
+
+    /**
+     * Download the file of a caption of a password protected video.
+     */
+    pub async fn download_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        caption: &crate::data::Caption,
+        password: &str,
+    ) -> crate::Result<String> {
+        self.fetch(auth.into(), caption, super::password_header(password)?)
+            .await
+    }
+
+    async fn fetch(
+        &self,
+        auth: crate::Auth,
+        caption: &crate::data::Caption,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<String> {
+        let url = format!("{}{}", self.config.base_url, caption.caption_path);
+        let mut request = self.config.client.get(url).headers(headers);
+
+        if let Some(token) = auth.token(&self.config).await? {
+            request = request.bearer_auth(token.access_token);
+        }
+
+        let response = request.send().await?;
+
+        if !response.status().is_success() {
+            return Err(crate::PeertubeError::from_response(response).await?.into());
+        }
+
+        Ok(response.text().await?)
+    }
+
+    /**
+     * Download and parse the file of a caption.
+     */
+    pub async fn cues(
+        &self,
+        caption: &crate::data::Caption,
+    ) -> crate::Result<Vec<crate::captions::Cue>> {
+        let text = self.download(caption).await?;
+
+        Ok(crate::captions::parse(&text)?)
+    }
+
+    /**
+     * Download and parse the file of a caption of a password protected video.
+     */
+    pub async fn cues_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        caption: &crate::data::Caption,
+        password: &str,
+    ) -> crate::Result<Vec<crate::captions::Cue>> {
+        let text = self.download_with_password(auth, caption, password).await?;
+
+        Ok(crate::captions::parse(&text)?)
+    }
+
     /**
      * Delete a video caption.
      */
-    pub async fn delete(
+    pub async fn delete<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
         language: &str,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{video_id}/captions/{language}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -99,4 +236,47 @@ mod test {
 
         assert!(status.is_ok());
     }
+
+    #[tokio::test]
+    async fn add_cues() {
+        let (api, token) = crate::test::api().await;
+        let srt = std::fs::read_to_string("fixtures/caption.srt").unwrap();
+        let cues = crate::captions::parse(&srt).unwrap();
+
+        let status = api
+            .videos
+            .captions
+            .add_cues(&token, "1cb3e9c4-2da6-4af3-804e-d4675c18e128", "fr", &cues)
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn cues() {
+        let (api, _) = crate::test::api().await;
+
+        let captions = api
+            .videos
+            .captions
+            .all("1cb3e9c4-2da6-4af3-804e-d4675c18e128")
+            .await
+            .unwrap();
+        let cues = api.videos.captions.cues(&captions.data[0]).await;
+
+        assert!(cues.is_ok());
+    }
+
+    #[tokio::test]
+    async fn generate() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .videos
+            .captions
+            .generate(&token, "1cb3e9c4-2da6-4af3-804e-d4675c18e128", false)
+            .await;
+
+        assert!(status.is_ok());
+    }
 }
@@ -0,0 +1,145 @@
+pub struct Chapters {
+    config: crate::Config,
+}
+
+impl Chapters {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * List chapters of a video.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+    ) -> crate::Result<Vec<crate::data::Chapter>> {
+        self.fetch(auth, id, Default::default()).await
+    }
+
+    /**
+     * List chapters of a password protected video.
+     */
+    pub async fn all_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        password: &str,
+    ) -> crate::Result<Vec<crate::data::Chapter>> {
+        self.fetch(auth, id, super::password_header(password)?)
+            .await
+    }
+
+    async fn fetch<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<Vec<crate::data::Chapter>> {
+        let request = crate::Request {
+            path: format!("/videos/{id}/chapters"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        let chapters: crate::data::Chapters =
+            crate::Api::request_with_headers(reqwest::Method::GET, &self.config, request, headers)
+                .await?;
+
+        Ok(chapters.chapters)
+    }
+
+    /**
+     * Replace all chapters of a video, an empty list removes them.
+     */
+    pub async fn replace<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        chapters: &[crate::data::Chapter],
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/videos/{id}/chapters"),
+            params: crate::Params::Json(crate::param::Chapters { chapters }),
+            auth: auth.into(),
+        };
+
+        crate::Api::put(&self.config, request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let chapters = api
+            .videos
+            .chapters
+            .all(Some(&token), "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(chapters.is_ok());
+    }
+
+    #[tokio::test]
+    async fn replace() {
+        let (api, token) = crate::test::api().await;
+        let chapters = crate::data::Chapter::parse("00:00 Intro\n01:30 Demo");
+
+        let status = api
+            .videos
+            .chapters
+            .replace(&token, "04193a18-7abc-4803-bec7-c75d9888256f", &chapters)
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[test]
+    fn parse() {
+        let description = "Talk given at the conference.
+
+Chapters:
+0:00 Intro
+(01:05) - Installation
+[1:02:03] | Questions
+12:345 Not a chapter
+00:10";
+
+        let chapters = crate::data::Chapter::parse(description);
+
+        assert_eq!(
+            chapters,
+            [
+                crate::data::Chapter {
+                    timecode: 0,
+                    title: "Intro".to_string(),
+                },
+                crate::data::Chapter {
+                    timecode: 65,
+                    title: "Installation".to_string(),
+                },
+                crate::data::Chapter {
+                    timecode: 3723,
+                    title: "Questions".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn format() {
+        let chapters =
+            crate::data::Chapter::parse("0:00 Intro\n1:05 Installation\n1:02:03 Questions");
+
+        assert_eq!(
+            crate::data::Chapter::format(&chapters),
+            "00:00 Intro\n01:05 Installation\n1:02:03 Questions"
+        );
+    }
+}
@@ -20,30 +20,65 @@ impl Comments {
         let request = crate::Request {
             path: format!("/videos/{video_id}/comment-threads"),
             params: crate::Params::Query(pagination),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
     }
 
     /**
-     * Create a thread.
+     * List threads of a password protected video.
      */
-    pub async fn create(
+    pub async fn all_with_password<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
-        text: &str,
-    ) -> crate::Result<crate::data::Comment> {
+        pagination: &crate::param::Pagination,
+        password: &str,
+    ) -> crate::Result<crate::Pager<crate::data::Comment>> {
         let request = crate::Request {
             path: format!("/videos/{video_id}/comment-threads"),
-            params: crate::Params::Json(crate::param::Comment {
-                text: text.to_string(),
-            }),
-            auth: Some(auth.clone()),
+            params: crate::Params::Query(pagination),
+            auth: auth.into(),
         };
 
-        crate::Api::post(&self.config, request).await
+        crate::Api::request_with_headers(
+            reqwest::Method::GET,
+            &self.config,
+            request,
+            super::password_header(password)?,
+        )
+        .await
+    }
+
+    /**
+     * Create a thread.
+     */
+    pub async fn create<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        text: &str,
+    ) -> crate::Result<crate::data::Comment> {
+        let path = format!("/videos/{video_id}/comment-threads");
+
+        self.post(auth, path, text, Default::default()).await
+    }
+
+    /**
+     * Create a thread on a password protected video.
+     */
+    pub async fn create_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        text: &str,
+        password: &str,
+    ) -> crate::Result<crate::data::Comment> {
+        let path = format!("/videos/{video_id}/comment-threads");
+
+        self.post(auth, path, text, super::password_header(password)?)
+            .await
     }
 
     /**
@@ -53,56 +88,110 @@ impl Comments {
         let request = crate::Request {
             path: format!("/videos/{video_id}/comment-threads/{thread_id}"),
             params: crate::Params::none(),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
     }
 
+    /**
+     * Get a thread of a password protected video.
+     */
+    pub async fn get_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        thread_id: u32,
+        password: &str,
+    ) -> crate::Result<crate::data::Thread> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/comment-threads/{thread_id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::request_with_headers(
+            reqwest::Method::GET,
+            &self.config,
+            request,
+            super::password_header(password)?,
+        )
+        .await
+    }
+
     /**
      * Reply to a thread of a video.
      */
-    pub async fn reply(
+    pub async fn reply<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
         comment_id: u32,
         text: &str,
     ) -> crate::Result<crate::data::Comment> {
-        let params = crate::param::Comment {
-            text: text.to_string(),
-        };
+        let path = format!("/videos/{video_id}/comments/{comment_id}");
+
+        self.post(auth, path, text, Default::default()).await
+    }
 
+    /**
+     * Reply to a thread of a password protected video.
+     */
+    pub async fn reply_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        comment_id: u32,
+        text: &str,
+        password: &str,
+    ) -> crate::Result<crate::data::Comment> {
+        let path = format!("/videos/{video_id}/comments/{comment_id}");
+
+        self.post(auth, path, text, super::password_header(password)?)
+            .await
+    }
+
+    async fn post<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        path: String,
+        text: &str,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::Result<crate::data::Comment> {
         let request = crate::Request {
-            path: format!("/videos/{video_id}/comments/{comment_id}"),
-            params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            path,
+            params: crate::Params::Json(crate::param::Comment {
+                text: text.to_string(),
+            }),
+            auth: auth.into(),
         };
 
-        match crate::Api::post(&self.config, request).await? {
+        let content =
+            crate::Api::request_with_headers(reqwest::Method::POST, &self.config, request, headers)
+                .await?;
+
+        match content {
             crate::data::NewContent::Comment(comment) => Ok(comment),
-            _ => unreachable!(),
+            content => Err(content.unexpected("comment")),
         }
     }
 
     /**
      * Delete a comment or a reply.
      */
-    pub async fn delete(
+    pub async fn delete<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
         comment_id: u32,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{video_id}/comments/{comment_id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
-        crate::Api::get::<crate::data::Empty, _>(&self.config, request)
-            .await?
-            .into()
+        crate::Api::delete(&self.config, request).await
     }
 }
 
@@ -141,6 +230,28 @@ mod test {
         assert!(comment.is_ok());
     }
 
+    #[tokio::test]
+    async fn create_unexpected() {
+        let (server, api, token) = crate::test::server().await;
+        server.mock(
+            "POST",
+            "/api/v1/videos/*/comment-threads",
+            crate::testing::Response::json(
+                serde_json::json!({ "video": { "id": 1, "uuid": "1" } }),
+            ),
+        );
+
+        let err = api
+            .videos
+            .comments
+            .create(&token, "1", "new comment")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::Error::UnexpectedResponse(_)));
+        assert_eq!(err.status(), None);
+    }
+
     #[tokio::test]
     async fn get() {
         let (api, _) = crate::test::api().await;
@@ -12,15 +12,15 @@ impl Live {
     /**
      * Create a live.
      */
-    pub async fn create(
+    pub async fn create<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Live,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: "/videos/live".to_string(),
-            params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(params)?,
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -28,16 +28,19 @@ impl Live {
 
     /**
      * Get information about a live.
+     *
+     * PeerTube has no endpoint to regenerate the stream key, a new key
+     * requires creating a new live.
      */
-    pub async fn get(
+    pub async fn get<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
     ) -> crate::Result<crate::data::Live> {
         let request = crate::Request {
             path: format!("/videos/live/{id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -46,20 +49,160 @@ impl Live {
     /**
      * Update information about a live.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         params: &crate::param::LiveSetting,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/live/{id}"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
     }
+
+    /**
+     * List the sessions of a live, most recent last.
+     */
+    pub async fn sessions<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+    ) -> crate::Result<crate::Pager<crate::data::LiveSession>> {
+        let request = crate::Request {
+            path: format!("/videos/live/{id}/sessions"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Get the live session a replay video comes from.
+     */
+    pub async fn session<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+    ) -> crate::Result<crate::data::LiveSession> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/live-session"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Fetch the live of the video `id`, or create a new live from `params`
+     * when `id` is `None`.
+     */
+    pub async fn create_or_get<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: Option<&str>,
+        params: &crate::param::Live,
+    ) -> crate::Result<crate::data::LiveStream> {
+        let auth: crate::Auth = auth.into();
+
+        let video_id = match id {
+            Some(id) => id.to_string(),
+            None => match self.create(&auth, params).await? {
+                crate::data::NewContent::Video(video) => video.uuid,
+                content => return Err(content.unexpected("video")),
+            },
+        };
+
+        let live = self.get(&auth, &video_id).await?;
+
+        Ok(crate::data::LiveStream { video_id, live })
+    }
+
+    /**
+     * Current state of the video of a live, `None` if the instance returns a
+     * state unknown to this crate.
+     */
+    pub async fn state<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+    ) -> crate::Result<Option<crate::data::VideoState>> {
+        super::state(&self.config, &auth.into(), id).await
+    }
+
+    /**
+     * Poll the video state every `interval` until it is one of `states`, see
+     * `Videos::wait`.
+     */
+    pub async fn wait<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        states: &[crate::data::VideoState],
+        interval: std::time::Duration,
+    ) -> crate::Result<crate::data::VideoState> {
+        super::wait(&self.config, &auth.into(), id, states, interval).await
+    }
+
+    /**
+     * Wait until the stream is received and the live is published.
+     *
+     * Fails with `Error::VideoState` if the live ends first, or with
+     * `Error::Timeout` if nothing is streamed within `timeout`.
+     */
+    pub async fn wait_published<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> crate::Result {
+        let published = self.wait(auth, id, &[crate::data::VideoState::Published], interval);
+
+        tokio::time::timeout(timeout, published).await??;
+
+        Ok(())
+    }
+
+    /**
+     * Poll the video state every `interval`, the stream yields the current
+     * state then every change.
+     *
+     * The stream never ends, drop it to stop watching.
+     */
+    pub fn watch<'a, A: Into<crate::Auth>>(
+        &'a self,
+        auth: A,
+        id: &'a str,
+        interval: std::time::Duration,
+    ) -> impl futures_util::Stream<Item = crate::Result<crate::data::VideoState>> + 'a {
+        let auth: crate::Auth = auth.into();
+
+        futures_util::stream::try_unfold(None, move |last| {
+            let auth = auth.clone();
+
+            async move {
+                if last.is_some() {
+                    tokio::time::sleep(interval).await;
+                }
+
+                loop {
+                    let state = self.state(&auth, id).await?;
+
+                    if state.is_some() && state != last {
+                        return Ok(state.map(|x| (x, state)));
+                    }
+
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -101,6 +244,8 @@ mod test {
         let params = crate::param::LiveSetting {
             save_replay: Some(true),
             permanent_live: Some(false),
+            replay_settings: Some(crate::data::ReplaySettings { privacy: 2 }),
+            latency_mode: Some(crate::data::LatencyMode::Small),
         };
 
         let status = api
@@ -111,4 +256,129 @@ mod test {
 
         assert!(status.is_ok());
     }
+
+    #[tokio::test]
+    async fn sessions() {
+        let (api, token) = crate::test::api().await;
+
+        let sessions = api
+            .videos
+            .live
+            .sessions(&token, "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(sessions.is_ok());
+    }
+
+    #[tokio::test]
+    async fn session() {
+        let (api, token) = crate::test::api().await;
+
+        let session = api
+            .videos
+            .live
+            .session(Some(&token), "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(session.is_ok());
+    }
+
+    #[tokio::test]
+    async fn create_or_get() {
+        let (api, token) = crate::test::api().await;
+
+        let params = crate::param::Live {
+            video: crate::param::NewVideo {
+                channel_id: "58edd166-dab0-4a1e-86e3-85778b78ba77".to_string(),
+                name: Some("live".to_string()),
+
+                ..Default::default()
+            },
+
+            ..Default::default()
+        };
+        let stream = api.videos.live.create_or_get(&token, None, &params).await;
+
+        assert!(stream.is_ok());
+    }
+
+    #[tokio::test]
+    async fn wait_published() {
+        use futures_util::TryStreamExt;
+
+        let (server, api, token) = crate::test::server().await;
+
+        let polls = std::sync::atomic::AtomicU32::new(0);
+        server.mock_fn("GET", "/api/v1/videos/*", move |_| {
+            let poll = polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut video = crate::testing::fixtures::video_details();
+            video["state"]["id"] = if poll < 2 { 4 } else { 1 }.into();
+
+            crate::testing::Response::json(video)
+        });
+
+        let live = api.videos.live.get(&token, "1").await.unwrap();
+        assert_eq!(
+            live.ingest_url(),
+            format!("{}/{}", live.rtmp_url, live.stream_key)
+        );
+
+        let interval = std::time::Duration::from_millis(10);
+        let mut states = Box::pin(api.videos.live.watch(&token, "1", interval));
+
+        assert_eq!(
+            states.try_next().await.unwrap(),
+            Some(crate::data::VideoState::WaitingForLive)
+        );
+        assert_eq!(
+            states.try_next().await.unwrap(),
+            Some(crate::data::VideoState::Published)
+        );
+        drop(states);
+
+        let published = api
+            .videos
+            .live
+            .wait_published(&token, "1", interval, std::time::Duration::from_secs(5))
+            .await;
+
+        assert!(published.is_ok());
+    }
+
+    #[tokio::test]
+    async fn wait_ended() {
+        let (server, api, token) = crate::test::server().await;
+        let interval = std::time::Duration::from_millis(10);
+        let timeout = std::time::Duration::from_millis(100);
+
+        let state = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(4));
+        let current = state.clone();
+        server.mock_fn("GET", "/api/v1/videos/*", move |_| {
+            let mut video = crate::testing::fixtures::video_details();
+            video["state"]["id"] = current.load(std::sync::atomic::Ordering::SeqCst).into();
+
+            crate::testing::Response::json(video)
+        });
+
+        let err = api
+            .videos
+            .live
+            .wait_published(&token, "1", interval, timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::Timeout(_)));
+
+        state.store(5, std::sync::atomic::Ordering::SeqCst);
+
+        let err = api
+            .videos
+            .live
+            .wait_published(&token, "1", interval, timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::VideoState(crate::data::VideoState::LiveEnded)
+        ));
+    }
 }
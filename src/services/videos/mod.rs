@@ -1,19 +1,31 @@
 mod captions;
+mod chapters;
 mod comments;
 mod live;
 mod ownership;
+mod passwords;
+mod resumable;
+mod source;
 
 use captions::*;
+use chapters::*;
 use comments::*;
 use live::*;
 use ownership::*;
+use passwords::*;
+use resumable::*;
+use source::*;
 
 pub struct Videos {
     config: crate::Config,
     pub captions: Captions,
+    pub chapters: Chapters,
     pub comments: Comments,
     pub live: Live,
     pub ownership: Ownership,
+    pub passwords: Passwords,
+    pub resumable: Resumable,
+    pub source: Source,
 }
 
 impl Videos {
@@ -21,9 +33,13 @@ impl Videos {
         Self {
             config: config.clone(),
             captions: Captions::new(config),
+            chapters: Chapters::new(config),
             comments: Comments::new(config),
             live: Live::new(config),
             ownership: Ownership::new(config),
+            passwords: Passwords::new(config),
+            resumable: Resumable::new(config),
+            source: Source::new(config),
         }
     }
 
@@ -37,7 +53,7 @@ impl Videos {
         let request = crate::Request {
             path: "/videos".to_string(),
             params: crate::Params::Query(params),
-            auth: None,
+            auth: crate::Auth::Anonymous,
         };
 
         crate::Api::get(&self.config, request).await
@@ -74,16 +90,16 @@ impl Videos {
     /**
      * Update a video.
      */
-    pub async fn update(
+    pub async fn update<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         params: &crate::param::Video,
     ) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{id}"),
-            params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(params)?,
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -92,18 +108,46 @@ impl Videos {
     /**
      * Get a video.
      */
-    pub async fn get(&self, id: &str) -> crate::Result<crate::data::Video> {
+    pub async fn get(&self, id: &str) -> crate::Result<crate::data::VideoDetails> {
         crate::Api::get(&self.config, format!("/videos/{id}").into()).await
     }
 
+    /**
+     * Get a password protected video.
+     *
+     * The password isn't needed for the owner of the video, with `auth`. The
+     * description, chapters, comments and captions of the video have
+     * `_with_password` methods too, taking the same arguments.
+     */
+    pub async fn get_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        password: &str,
+    ) -> crate::Result<crate::data::VideoDetails> {
+        let request = crate::Request {
+            path: format!("/videos/{id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::request_with_headers(
+            reqwest::Method::GET,
+            &self.config,
+            request,
+            password_header(password)?,
+        )
+        .await
+    }
+
     /**
      * Delete a video.
      */
-    pub async fn delete(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{id}"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -120,6 +164,33 @@ impl Videos {
         Ok(description.description)
     }
 
+    /**
+     * Get complete description of a password protected video.
+     */
+    #[cfg_attr(feature = "v5", deprecated)]
+    pub async fn description_with_password<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        password: &str,
+    ) -> crate::Result<String> {
+        let request = crate::Request {
+            path: format!("/videos/{id}/description"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        let description: crate::data::Description = crate::Api::request_with_headers(
+            reqwest::Method::GET,
+            &self.config,
+            request,
+            password_header(password)?,
+        )
+        .await?;
+
+        Ok(description.description)
+    }
+
     /**
      * Add a view to a video.
      */
@@ -135,9 +206,9 @@ impl Videos {
     /**
      * Set watching progress of a video.
      */
-    pub async fn set_watching(
+    pub async fn set_watching<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         current_time: u32,
     ) -> crate::Result<()> {
@@ -146,7 +217,7 @@ impl Videos {
         let request = crate::Request {
             path: format!("/videos/{id}/watching"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -155,33 +226,92 @@ impl Videos {
     /**
      * Upload a video.
      */
-    pub async fn upload(
+    pub async fn upload<A: Into<crate::Auth>, F: crate::IntoFile>(
         &self,
-        auth: &crate::data::Token,
-        videofile: &str,
+        auth: A,
+        videofile: F,
         params: &crate::param::NewVideo,
     ) -> crate::Result<crate::data::NewContent> {
         let request = crate::Request {
             path: "/videos/upload".to_string(),
-            params: crate::Params::upload(params, "videofile", videofile)?,
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(params)?.file("videofile", videofile)?,
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
     }
 
+    /**
+     * Edit a video with Video Studio.
+     *
+     * The video is in the `VideoState::ToEdit` state until the edition is
+     * done, see `Videos::wait`.
+     */
+    pub async fn edit<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        edit: crate::param::StudioEdit,
+    ) -> crate::Result<()> {
+        let fields: std::collections::BTreeMap<_, _> = crate::fields(&edit)?.into_iter().collect();
+        let mut params = crate::Params::multipart(fields)?;
+
+        for (name, file) in edit.into_files() {
+            params = params.file(&name, file)?;
+        }
+
+        let request = crate::Request {
+            path: format!("/videos/{id}/studio/edit"),
+            params,
+            auth: auth.into(),
+        };
+
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
+    }
+
+    /**
+     * Current state of a video, `None` if the instance returns a state
+     * unknown to this crate.
+     */
+    pub async fn state<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+    ) -> crate::Result<Option<crate::data::VideoState>> {
+        state(&self.config, &auth.into(), id).await
+    }
+
+    /**
+     * Poll the video state every `interval` until it is one of `states`.
+     *
+     * Fails with `Error::VideoState` if the video reaches a terminal state
+     * not in `states`, see `VideoState::is_terminal`. Wrap in
+     * `tokio::time::timeout` to give up after some time.
+     */
+    pub async fn wait<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        states: &[crate::data::VideoState],
+        interval: std::time::Duration,
+    ) -> crate::Result<crate::data::VideoState> {
+        wait(&self.config, &auth.into(), id, states, interval).await
+    }
+
     /**
      * Import a video.
      */
-    pub async fn import(
+    pub async fn import<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         params: &crate::param::Import,
     ) -> crate::Result<crate::data::NewImport> {
         let request = crate::Request {
             path: "/videos/imports".to_string(),
-            params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            params: crate::Params::with_files(params)?,
+            auth: auth.into(),
         };
 
         crate::Api::post(&self.config, request).await
@@ -190,9 +320,9 @@ impl Videos {
     /**
      * Like/dislike a video.
      */
-    pub async fn rate(
+    pub async fn rate<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         id: &str,
         rate: crate::param::Rating,
     ) -> crate::Result<()> {
@@ -205,7 +335,7 @@ impl Videos {
         let request = crate::Request {
             path: format!("/videos/{id}/rate"),
             params: crate::Params::Json(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::put(&self.config, request).await
@@ -214,11 +344,11 @@ impl Videos {
     /**
      * Block a video.
      */
-    pub async fn block(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn block<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{id}/blacklist"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::post::<crate::data::Empty, _>(&self.config, request)
@@ -229,11 +359,11 @@ impl Videos {
     /**
      * Unblock a video.
      */
-    pub async fn unblock(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn unblock<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/{id}/blacklist"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::delete(&self.config, request).await
@@ -242,21 +372,68 @@ impl Videos {
     /**
      * List video blocks.
      */
-    pub async fn blacklist(
+    pub async fn blacklist<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         pagination: &crate::param::VideoBlacklists,
     ) -> crate::Result<crate::Pager<crate::data::VideoBlacklist>> {
         let request = crate::Request {
             path: "/videos/blacklist".to_string(),
             params: crate::Params::Query(pagination),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
     }
 }
 
+/**
+ * Header giving access to a password protected video.
+ */
+fn password_header(password: &str) -> crate::Result<reqwest::header::HeaderMap> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        "x-peertube-video-password",
+        reqwest::header::HeaderValue::from_bytes(password.as_bytes())?,
+    );
+
+    Ok(headers)
+}
+
+async fn state(
+    config: &crate::Config,
+    auth: &crate::Auth,
+    id: &str,
+) -> crate::Result<Option<crate::data::VideoState>> {
+    let request = crate::Request {
+        path: format!("/videos/{id}"),
+        params: crate::Params::none(),
+        auth: auth.clone(),
+    };
+
+    let video: crate::data::VideoDetails = crate::Api::get(config, request).await?;
+
+    Ok(video.state.as_ref().and_then(crate::data::State::kind))
+}
+
+async fn wait(
+    config: &crate::Config,
+    auth: &crate::Auth,
+    id: &str,
+    states: &[crate::data::VideoState],
+    interval: std::time::Duration,
+) -> crate::Result<crate::data::VideoState> {
+    loop {
+        match state(config, auth, id).await? {
+            Some(state) if states.contains(&state) => return Ok(state),
+            Some(state) if state.is_terminal() => return Err(crate::Error::VideoState(state)),
+            _ => (),
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[tokio::test]
@@ -329,6 +506,20 @@ mod test {
         assert!(video.is_ok());
     }
 
+    #[tokio::test]
+    async fn files() {
+        let (api, _) = crate::test::api().await;
+
+        let video = api
+            .videos
+            .get("1cb3e9c4-2da6-4af3-804e-d4675c18e128")
+            .await
+            .unwrap();
+
+        assert!(video.hls_playlist().is_some());
+        assert!(video.best_file().is_some());
+    }
+
     #[tokio::test]
     async fn delete() {
         let (api, token) = crate::test::api().await;
@@ -395,6 +586,112 @@ mod test {
         assert!(video.is_ok());
     }
 
+    #[tokio::test]
+    async fn get_with_password() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+
+        let video = server
+            .api()
+            .videos
+            .get_with_password(None, "1", "secret")
+            .await;
+
+        assert!(video.is_ok());
+        assert_eq!(
+            server.requests()[0].header("x-peertube-video-password"),
+            Some("secret")
+        );
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn related_with_password() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let videos = server.api().videos;
+
+        let captions = videos
+            .captions
+            .all_with_password(None, "1", "secret")
+            .await
+            .unwrap();
+        videos
+            .captions
+            .cues_with_password(None, &captions.data[0], "secret")
+            .await
+            .unwrap();
+        videos
+            .description_with_password(None, "1", "secret")
+            .await
+            .unwrap();
+        videos
+            .chapters
+            .all_with_password(None, "1", "secret")
+            .await
+            .unwrap();
+        videos
+            .comments
+            .all_with_password(None, "1", &Default::default(), "secret")
+            .await
+            .unwrap();
+        videos
+            .comments
+            .get_with_password(None, "1", 1, "secret")
+            .await
+            .unwrap();
+        videos
+            .comments
+            .create_with_password(None, "1", "comment", "secret")
+            .await
+            .unwrap();
+        videos
+            .comments
+            .reply_with_password(None, "1", 1, "reply", "secret")
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests.len(), 8);
+        assert!(
+            requests
+                .iter()
+                .all(|x| x.header("x-peertube-video-password") == Some("secret"))
+        );
+    }
+
+    #[tokio::test]
+    async fn edit() {
+        let (server, api, token) = crate::test::server().await;
+
+        let edit = crate::param::StudioEdit::default()
+            .cut(Some(5), None)
+            .add_watermark("fixtures/avatar.png")
+            .unwrap();
+        api.videos.edit(&token, "1", edit).await.unwrap();
+
+        let request = server.requests().pop().unwrap();
+        let body = request.text();
+
+        assert_eq!(request.path, "/api/v1/videos/1/studio/edit");
+        assert!(body.contains("name=\"tasks[0][name]\"\r\n\r\ncut\r\n"));
+        assert!(body.contains("name=\"tasks[0][options][start]\"\r\n\r\n5\r\n"));
+        assert!(!body.contains("tasks[0][options][end]"));
+        assert!(body.contains("name=\"tasks[1][name]\"\r\n\r\nadd-watermark\r\n"));
+        assert!(body.contains("name=\"tasks[1][options][file]\"; filename=\"avatar.png\""));
+
+        let state = api
+            .videos
+            .wait(
+                &token,
+                "1",
+                &[crate::data::VideoState::Published],
+                std::time::Duration::from_millis(10),
+            )
+            .await;
+
+        assert!(state.is_ok());
+    }
+
     #[tokio::test]
     async fn import() {
         let (api, token) = crate::test::api().await;
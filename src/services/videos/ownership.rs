@@ -12,14 +12,14 @@ impl Ownership {
     /**
      * List video ownership changes.
      */
-    pub async fn all(
+    pub async fn all<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
     ) -> crate::Result<crate::Pager<crate::data::Ownership>> {
         let request = crate::Request {
             path: "/videos/ownership".to_string(),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
         crate::Api::get(&self.config, request).await
@@ -28,35 +28,39 @@ impl Ownership {
     /**
      * Accept ownership change request.
      */
-    pub async fn accept(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn accept<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/ownership/{id}/accept"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
-        crate::Api::post(&self.config, request).await
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
     }
 
     /**
      * Refuse ownership change request.
      */
-    pub async fn refuse(&self, auth: &crate::data::Token, id: &str) -> crate::Result<()> {
+    pub async fn refuse<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
         let request = crate::Request {
             path: format!("/videos/ownership/{id}/refuse"),
             params: crate::Params::none(),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
-        crate::Api::post(&self.config, request).await
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
     }
 
     /**
      * Request ownership change.
      */
-    pub async fn create(
+    pub async fn create<A: Into<crate::Auth>>(
         &self,
-        auth: &crate::data::Token,
+        auth: A,
         video_id: &str,
         username: &str,
     ) -> crate::Result<()> {
@@ -67,10 +71,12 @@ impl Ownership {
         let request = crate::Request {
             path: format!("/videos/{video_id}/give-ownership"),
             params: crate::Params::Form(params),
-            auth: Some(auth.clone()),
+            auth: auth.into(),
         };
 
-        crate::Api::post(&self.config, request).await
+        crate::Api::post::<crate::data::Empty, _>(&self.config, request)
+            .await?
+            .into()
     }
 }
 
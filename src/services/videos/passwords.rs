@@ -0,0 +1,159 @@
+use futures_util::TryStreamExt;
+
+pub struct Passwords {
+    config: crate::Config,
+}
+
+impl Passwords {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * List passwords of a password protected video.
+     */
+    pub async fn all<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        pagination: &crate::param::Pagination,
+    ) -> crate::Result<crate::Pager<crate::data::VideoPassword>> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/passwords"),
+            params: crate::Params::Query(pagination),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Replace all passwords of a video.
+     */
+    pub async fn replace<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        passwords: &[String],
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/passwords"),
+            params: crate::Params::Json(crate::param::VideoPasswords { passwords }),
+            auth: auth.into(),
+        };
+
+        crate::Api::put(&self.config, request).await
+    }
+
+    /**
+     * Add a password to a video.
+     *
+     * PeerTube has no endpoint for this, the existing passwords are fetched
+     * then replaced: concurrent changes can be lost.
+     */
+    pub async fn add<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        password: &str,
+    ) -> crate::Result<()> {
+        let auth: &crate::Auth = &auth.into();
+
+        let mut passwords = crate::Paginator::new(|pagination| async move {
+            self.all(auth, video_id, &pagination).await
+        })
+        .into_stream()
+        .map_ok(|x| x.password)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        if !passwords.iter().any(|x| x == password) {
+            passwords.push(password.to_string());
+        }
+
+        self.replace(auth, video_id, &passwords).await
+    }
+
+    /**
+     * Delete a password of a video, the last password can't be deleted.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        video_id: &str,
+        password_id: u32,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/videos/{video_id}/passwords/{password_id}"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn all() {
+        let (api, token) = crate::test::api().await;
+
+        let passwords = api
+            .videos
+            .passwords
+            .all(
+                &token,
+                "04193a18-7abc-4803-bec7-c75d9888256f",
+                &crate::param::Pagination::default(),
+            )
+            .await;
+
+        assert!(passwords.is_ok());
+    }
+
+    #[tokio::test]
+    async fn replace() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .videos
+            .passwords
+            .replace(
+                &token,
+                "04193a18-7abc-4803-bec7-c75d9888256f",
+                &["secret".to_string()],
+            )
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn add() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .videos
+            .passwords
+            .add(&token, "04193a18-7abc-4803-bec7-c75d9888256f", "other")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .videos
+            .passwords
+            .delete(&token, "04193a18-7abc-4803-bec7-c75d9888256f", 1)
+            .await;
+
+        assert!(status.is_ok());
+    }
+}
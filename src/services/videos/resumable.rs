@@ -0,0 +1,367 @@
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+pub struct Resumable {
+    config: crate::Config,
+}
+
+impl Resumable {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * Initialize the resumable upload of a video.
+     *
+     * The thumbnail and preview files of `params` are sent with this request.
+     *
+     * The returned session can be saved to continue the upload later, possibly
+     * from another process.
+     */
+    pub async fn init<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        videofile: &str,
+        params: &crate::param::NewVideo,
+    ) -> crate::Result<crate::data::UploadSession> {
+        init(
+            &self.config,
+            &auth.into(),
+            "/videos/upload-resumable",
+            videofile,
+            params,
+        )
+        .await
+    }
+
+    /**
+     * Send the video file, starting from the last byte received by the
+     * server.
+     *
+     * `progress` is called after each chunk with the number of bytes sent and
+     * the file size.
+     */
+    pub async fn upload<A: Into<crate::Auth>, F: FnMut(u64, u64)>(
+        &self,
+        auth: A,
+        session: &crate::data::UploadSession,
+        progress: F,
+    ) -> crate::Result<crate::data::NewContent> {
+        upload(&self.config, &auth.into(), session, progress).await
+    }
+
+    /**
+     * Get the number of bytes already received by the server.
+     */
+    pub async fn offset<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        session: &crate::data::UploadSession,
+    ) -> crate::Result<u64> {
+        match status(&self.config, &auth.into(), session).await? {
+            Status::Partial(offset) => Ok(offset),
+            Status::Done(_) => Ok(session.size),
+        }
+    }
+
+    /**
+     * Cancel a resumable upload.
+     */
+    pub async fn cancel<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        session: &crate::data::UploadSession,
+    ) -> crate::Result<()> {
+        let request = crate::Request {
+            path: session.path(),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+}
+
+enum Status {
+    Partial(u64),
+    Done(reqwest::Response),
+}
+
+pub(crate) async fn init<P: serde::Serialize + crate::param::Files>(
+    config: &crate::Config,
+    auth: &crate::Auth,
+    endpoint: &str,
+    file: &str,
+    params: P,
+) -> crate::Result<crate::data::UploadSession> {
+    let size = tokio::fs::metadata(file).await?.len();
+    let content_type = crate::mime::guess(file);
+    let filename = std::path::Path::new(file)
+        .file_name()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-upload-content-length", size.into());
+    headers.insert(
+        "x-upload-content-type",
+        reqwest::header::HeaderValue::from_static(content_type),
+    );
+
+    let request = crate::Request {
+        path: endpoint.to_string(),
+        params: crate::Params::json_or_files(crate::param::ResumableUpload { params, filename })?,
+        auth: auth.clone(),
+    };
+
+    let response = crate::Api::execute(reqwest::Method::POST, config, request, headers).await?;
+
+    if !response.status().is_success() {
+        return Err(crate::PeertubeError::from_response(response).await?.into());
+    }
+
+    let upload_id = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.split_once("upload_id="))
+        .map(|(_, id)| id.split('&').next().unwrap_or(id).to_string())
+        .ok_or_else(|| {
+            crate::Error::UnexpectedResponse("missing upload id in location header".to_string())
+        })?;
+
+    Ok(crate::data::UploadSession {
+        endpoint: endpoint.to_string(),
+        upload_id,
+        file: file.into(),
+        size,
+        content_type: content_type.to_string(),
+        chunk_size: crate::data::UploadSession::DEFAULT_CHUNK_SIZE,
+    })
+}
+
+pub(crate) async fn upload<T, F>(
+    config: &crate::Config,
+    auth: &crate::Auth,
+    session: &crate::data::UploadSession,
+    mut progress: F,
+) -> crate::Result<T>
+where
+    T: for<'de> serde::Deserialize<'de>,
+    F: FnMut(u64, u64),
+{
+    let mut offset = match status(config, auth, session).await? {
+        Status::Partial(offset) => offset,
+        Status::Done(response) => return Ok(response.json().await?),
+    };
+
+    let mut file = tokio::fs::File::open(&session.file).await?;
+
+    loop {
+        progress(offset, session.size);
+
+        let mut chunk = Vec::with_capacity(session.chunk_size);
+        file.seek(std::io::SeekFrom::Start(offset)).await?;
+        (&mut file)
+            .take(session.chunk_size as u64)
+            .read_to_end(&mut chunk)
+            .await?;
+
+        if chunk.is_empty() {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+
+        let end = offset + chunk.len() as u64 - 1;
+        let range = format!("bytes {offset}-{end}/{}", session.size);
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::CONTENT_RANGE, range.parse().unwrap());
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/octet-stream"),
+        );
+
+        let request = crate::Request {
+            path: session.path(),
+            params: crate::Params::<()>::Bytes(chunk),
+            auth: auth.clone(),
+        };
+
+        let response = crate::Api::execute(reqwest::Method::PUT, config, request, headers).await?;
+
+        match into_status(response).await? {
+            // The server would answer the same way to the same chunk
+            Status::Partial(received) if received <= offset => {
+                return Err(crate::Error::UnexpectedResponse(format!(
+                    "no byte received after {offset}"
+                )));
+            }
+            Status::Partial(received) => offset = received,
+            Status::Done(response) => {
+                progress(session.size, session.size);
+
+                return Ok(response.json().await?);
+            }
+        }
+    }
+}
+
+async fn status(
+    config: &crate::Config,
+    auth: &crate::Auth,
+    session: &crate::data::UploadSession,
+) -> crate::Result<Status> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::CONTENT_RANGE,
+        format!("bytes */{}", session.size).parse().unwrap(),
+    );
+
+    let request = crate::Request {
+        path: session.path(),
+        params: crate::Params::<()>::Bytes(Vec::new()),
+        auth: auth.clone(),
+    };
+
+    let response = crate::Api::execute(reqwest::Method::PUT, config, request, headers).await?;
+
+    into_status(response).await
+}
+
+async fn into_status(response: reqwest::Response) -> crate::Result<Status> {
+    if response.status() == reqwest::StatusCode::PERMANENT_REDIRECT {
+        // `Range: bytes=0-<last received byte>`, missing if nothing was received
+        let offset = response
+            .headers()
+            .get(reqwest::header::RANGE)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.rsplit_once('-'))
+            .and_then(|(_, end)| end.parse::<u64>().ok())
+            .map_or(0, |end| end + 1);
+
+        Ok(Status::Partial(offset))
+    } else if response.status().is_success() {
+        Ok(Status::Done(response))
+    } else {
+        Err(crate::PeertubeError::from_response(response).await?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn upload() {
+        let (api, token) = crate::test::api().await;
+        let params = crate::param::NewVideo {
+            channel_id: "58edd166-dab0-4a1e-86e3-85778b78ba77".to_string(),
+            name: Some("test".to_string()),
+
+            ..Default::default()
+        };
+
+        let session = api
+            .videos
+            .resumable
+            .init(&token, "fixtures/video.mp4", &params)
+            .await
+            .unwrap();
+
+        let video = api
+            .videos
+            .resumable
+            .upload(&token, &session, |_, _| ())
+            .await;
+
+        assert!(video.is_ok());
+    }
+
+    #[tokio::test]
+    async fn init_with_thumbnail() {
+        let (server, api, token) = crate::test::server().await;
+        let params = crate::param::NewVideo {
+            channel_id: "1".to_string(),
+            thumbnailfile: Some("fixtures/avatar.png".to_string()),
+
+            ..Default::default()
+        };
+
+        api.videos
+            .resumable
+            .init(&token, "fixtures/video.mp4", &params)
+            .await
+            .unwrap();
+
+        let request = server.requests().pop().unwrap();
+
+        assert!(
+            request
+                .header("content-type")
+                .unwrap()
+                .starts_with("multipart/form-data")
+        );
+        assert!(request.text().contains("name=\"thumbnailfile\""));
+        assert!(request.text().contains("name=\"filename\""));
+    }
+
+    #[tokio::test]
+    async fn upload_stalled() {
+        let (server, api, token) = crate::test::server().await;
+        server.mock(
+            "PUT",
+            "/api/v1/videos/upload-resumable",
+            crate::testing::Response::empty().status(308),
+        );
+        let params = crate::param::NewVideo {
+            channel_id: "1".to_string(),
+
+            ..Default::default()
+        };
+
+        let session = api
+            .videos
+            .resumable
+            .init(&token, "fixtures/video.mp4", &params)
+            .await
+            .unwrap();
+
+        let err = api
+            .videos
+            .resumable
+            .upload(&token, &session, |_, _| ())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::Error::UnexpectedResponse(_)));
+        assert_eq!(
+            server
+                .requests()
+                .iter()
+                .filter(|x| x.method == "PUT")
+                .count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel() {
+        let (api, token) = crate::test::api().await;
+        let params = crate::param::NewVideo {
+            channel_id: "58edd166-dab0-4a1e-86e3-85778b78ba77".to_string(),
+            name: Some("test".to_string()),
+
+            ..Default::default()
+        };
+
+        let session = api
+            .videos
+            .resumable
+            .init(&token, "fixtures/video.mp4", &params)
+            .await
+            .unwrap();
+
+        let status = api.videos.resumable.cancel(&token, &session).await;
+
+        assert!(status.is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+pub struct Source {
+    config: crate::Config,
+}
+
+impl Source {
+    pub(crate) fn new(config: &crate::Config) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    /**
+     * Get information about the original file of a video.
+     */
+    pub async fn get<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+    ) -> crate::Result<crate::data::VideoSource> {
+        let request = crate::Request {
+            path: format!("/videos/{id}/source"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::get(&self.config, request).await
+    }
+
+    /**
+     * Delete the original files of a video, the transcoded files are kept.
+     */
+    pub async fn delete<A: Into<crate::Auth>>(&self, auth: A, id: &str) -> crate::Result<()> {
+        let request = crate::Request {
+            path: format!("/videos/{id}/source/file"),
+            params: crate::Params::none(),
+            auth: auth.into(),
+        };
+
+        crate::Api::delete(&self.config, request).await
+    }
+
+    /**
+     * Initialize the resumable replacement of the file of a video, comments,
+     * views and other metadata are kept.
+     *
+     * The returned session can be saved to continue the upload later, possibly
+     * from another process.
+     */
+    pub async fn init_replace<A: Into<crate::Auth>>(
+        &self,
+        auth: A,
+        id: &str,
+        videofile: &str,
+    ) -> crate::Result<crate::data::UploadSession> {
+        super::resumable::init(
+            &self.config,
+            &auth.into(),
+            &format!("/videos/{id}/source/replace-resumable"),
+            videofile,
+            (),
+        )
+        .await
+    }
+
+    /**
+     * Send the new video file, see `Resumable::upload`.
+     */
+    pub async fn replace<A: Into<crate::Auth>, F: FnMut(u64, u64)>(
+        &self,
+        auth: A,
+        session: &crate::data::UploadSession,
+        progress: F,
+    ) -> crate::Result<()> {
+        super::resumable::upload::<crate::data::Empty, _>(
+            &self.config,
+            &auth.into(),
+            session,
+            progress,
+        )
+        .await?
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn get() {
+        let (api, token) = crate::test::api().await;
+
+        let source = api
+            .videos
+            .source
+            .get(&token, "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(source.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let (api, token) = crate::test::api().await;
+
+        let status = api
+            .videos
+            .source
+            .delete(&token, "04193a18-7abc-4803-bec7-c75d9888256f")
+            .await;
+
+        assert!(status.is_ok());
+    }
+
+    #[tokio::test]
+    async fn replace() {
+        let (api, token) = crate::test::api().await;
+
+        let session = api
+            .videos
+            .source
+            .init_replace(
+                &token,
+                "04193a18-7abc-4803-bec7-c75d9888256f",
+                "fixtures/video.mp4",
+            )
+            .await
+            .unwrap();
+
+        let status = api.videos.source.replace(&token, &session, |_, _| ()).await;
+
+        assert!(status.is_ok());
+    }
+}
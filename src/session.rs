@@ -0,0 +1,262 @@
+/**
+ * Access tokens are refreshed this long before their announced expiration.
+ */
+const EXPIRATION_MARGIN: i64 = 30;
+
+/**
+ * Credentials obtained by `Api::auth`, shared by every service.
+ */
+#[derive(Default)]
+pub(crate) struct Session {
+    credentials: tokio::sync::Mutex<Option<Credentials>>,
+    persistence: std::sync::RwLock<Option<Persistence>>,
+}
+
+/**
+ * Where `SessionManager` saves the credentials each time they change.
+ */
+struct Persistence {
+    store: std::sync::Arc<dyn crate::sessions::TokenStore>,
+    name: String,
+    username: String,
+}
+
+struct Credentials {
+    client: crate::data::OauthClient,
+    token: crate::data::Token,
+    expires_at: chrono::DateTime<chrono::offset::Utc>,
+    /**
+     * Every access token obtained since the authentication, still accepted
+     * from callers which haven't seen the most recent one.
+     */
+    issued: std::collections::HashSet<String>,
+}
+
+impl Credentials {
+    fn new(client: crate::data::OauthClient, token: crate::data::Token) -> Self {
+        Self::with_expiration(client, expires_at(&token), token)
+    }
+
+    fn with_expiration(
+        client: crate::data::OauthClient,
+        expires_at: chrono::DateTime<chrono::offset::Utc>,
+        token: crate::data::Token,
+    ) -> Self {
+        Self {
+            issued: std::collections::HashSet::from([token.access_token.clone()]),
+            expires_at,
+            client,
+            token,
+        }
+    }
+
+    fn knows(&self, token: &crate::data::Token) -> bool {
+        self.issued.contains(&token.access_token)
+    }
+
+    fn saved(&self, instance: &str, username: &str) -> crate::sessions::SavedSession {
+        crate::sessions::SavedSession {
+            instance: instance.to_string(),
+            username: username.to_string(),
+            client_id: self.client.client_id.clone(),
+            client_secret: self.client.client_secret.clone(),
+            token: self.token.clone(),
+            expires_at: self.expires_at,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        chrono::Utc::now() + chrono::Duration::seconds(EXPIRATION_MARGIN) >= self.expires_at
+    }
+
+    async fn refresh(&mut self, config: &crate::Config) -> crate::Result<crate::data::Token> {
+        let params = crate::param::RefreshToken {
+            client_id: self.client.client_id.clone(),
+            client_secret: self.client.client_secret.clone(),
+            grant_type: "refresh_token".to_string(),
+            refresh_token: self.token.refresh_token.clone(),
+        };
+
+        let response = config
+            .client
+            .post(format!("{}/api/v1/users/token", config.base_url))
+            .form(&params)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(crate::PeertubeError::from_response(response).await?.into());
+        }
+
+        self.token = response.json().await?;
+        self.issued.insert(self.token.access_token.clone());
+        self.expires_at = expires_at(&self.token);
+
+        Ok(self.token.clone())
+    }
+}
+
+fn expires_at(token: &crate::data::Token) -> chrono::DateTime<chrono::offset::Utc> {
+    chrono::Utc::now() + chrono::Duration::seconds(token.expires_in.into())
+}
+
+impl Session {
+    /**
+     * Uses new credentials, they aren't saved until `persist` is called again
+     * since they may belong to another account.
+     */
+    pub(crate) async fn set(&self, client: crate::data::OauthClient, token: crate::data::Token) {
+        let mut credentials = self.credentials.lock().await;
+
+        *self.persistence.write().unwrap() = None;
+        *credentials = Some(Credentials::new(client, token));
+    }
+
+    /**
+     * Uses credentials saved by a previous session, like `set`.
+     */
+    pub(crate) async fn restore(&self, saved: crate::sessions::SavedSession) {
+        let client = crate::data::OauthClient {
+            client_id: saved.client_id,
+            client_secret: saved.client_secret,
+        };
+
+        let mut credentials = self.credentials.lock().await;
+
+        *self.persistence.write().unwrap() = None;
+        *credentials = Some(Credentials::with_expiration(
+            client,
+            saved.expires_at,
+            saved.token,
+        ));
+    }
+
+    /**
+     * Saves the current credentials in `store`, then again after each
+     * refresh.
+     */
+    pub(crate) async fn persist(
+        &self,
+        config: &crate::Config,
+        store: std::sync::Arc<dyn crate::sessions::TokenStore>,
+        name: &str,
+        username: &str,
+    ) -> crate::Result<()> {
+        *self.persistence.write().unwrap() = Some(Persistence {
+            store,
+            name: name.to_string(),
+            username: username.to_string(),
+        });
+
+        match self.credentials.lock().await.as_ref() {
+            Some(credentials) => self.save(config, credentials),
+            None => Ok(()),
+        }
+    }
+
+    /**
+     * Forgets the credentials, they aren't saved anymore.
+     */
+    pub(crate) async fn clear(&self) {
+        *self.persistence.write().unwrap() = None;
+        *self.credentials.lock().await = None;
+    }
+
+    /**
+     * Forgets the credentials if `token` was issued by this session.
+     */
+    pub(crate) async fn forget(&self, token: &crate::data::Token) {
+        let mut credentials = self.credentials.lock().await;
+
+        if credentials.as_ref().is_some_and(|x| x.knows(token)) {
+            *self.persistence.write().unwrap() = None;
+            *credentials = None;
+        }
+    }
+
+    async fn refresh(
+        &self,
+        config: &crate::Config,
+        credentials: &mut Credentials,
+    ) -> crate::Result<crate::data::Token> {
+        let token = credentials.refresh(config).await?;
+        self.save(config, credentials)?;
+
+        Ok(token)
+    }
+
+    fn save(&self, config: &crate::Config, credentials: &Credentials) -> crate::Result<()> {
+        match self.persistence.read().unwrap().as_ref() {
+            Some(persistence) => persistence.store.save(
+                &persistence.name,
+                &credentials.saved(&config.base_url, &persistence.username),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /**
+     * Returns the current token, refreshed if it is about to expire.
+     */
+    pub(crate) async fn token(
+        &self,
+        config: &crate::Config,
+    ) -> crate::Result<Option<crate::data::Token>> {
+        let mut credentials = self.credentials.lock().await;
+
+        let Some(credentials) = credentials.as_mut() else {
+            return Ok(None);
+        };
+
+        if credentials.is_expired() {
+            self.refresh(config, credentials).await.map(Some)
+        } else {
+            Ok(Some(credentials.token.clone()))
+        }
+    }
+
+    /**
+     * Replaces a token issued by this session with its most recent version.
+     *
+     * Tokens unknown to the session are used as is.
+     */
+    pub(crate) async fn resolve(
+        &self,
+        config: &crate::Config,
+        token: &crate::data::Token,
+    ) -> crate::Result<crate::data::Token> {
+        let mut credentials = self.credentials.lock().await;
+
+        match credentials.as_mut() {
+            Some(credentials) if credentials.knows(token) => {
+                if credentials.is_expired() {
+                    self.refresh(config, credentials).await
+                } else {
+                    Ok(credentials.token.clone())
+                }
+            }
+            _ => Ok(token.clone()),
+        }
+    }
+
+    /**
+     * Called when the server rejected `token`, returns a new token to retry
+     * with or `None` if this session can't help.
+     */
+    pub(crate) async fn renew(
+        &self,
+        config: &crate::Config,
+        token: &crate::data::Token,
+    ) -> crate::Result<Option<crate::data::Token>> {
+        let mut credentials = self.credentials.lock().await;
+
+        match credentials.as_mut() {
+            Some(credentials) if credentials.token.access_token == token.access_token => {
+                self.refresh(config, credentials).await.map(Some)
+            }
+            // Already refreshed by a concurrent request.
+            Some(credentials) if credentials.knows(token) => Ok(Some(credentials.token.clone())),
+            _ => Ok(None),
+        }
+    }
+}
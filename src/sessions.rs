@@ -0,0 +1,436 @@
+/*!
+ * Saved credentials, to use an account from several processes without its
+ * password.
+ *
+ * ```no_run
+ * # async fn f() -> peertube::Result {
+ * use peertube::sessions::{FileStore, SessionManager};
+ *
+ * let sessions = SessionManager::new(FileStore::new("sessions.json"));
+ *
+ * // Once
+ * let api = peertube::Api::new("https://peertube.example.org");
+ * sessions.login("admin", &api, "root", "password").await?;
+ *
+ * // Later, from another process
+ * if let Some(api) = sessions.open("admin").await? {
+ *     let me = api.me.info(peertube::Auth::Session).await?;
+ * }
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+/**
+ * Credentials saved by a `TokenStore`.
+ */
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSession {
+    /**
+     * Base URL of the instance.
+     */
+    pub instance: String,
+    pub username: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub token: crate::data::Token,
+    pub expires_at: chrono::DateTime<chrono::offset::Utc>,
+}
+
+/**
+ * Storage of saved sessions, by name.
+ */
+pub trait TokenStore: Send + Sync {
+    fn load(&self, name: &str) -> crate::Result<Option<SavedSession>>;
+
+    fn save(&self, name: &str, session: &SavedSession) -> crate::Result<()>;
+
+    /**
+     * Removes a session, unknown names are ignored.
+     */
+    fn remove(&self, name: &str) -> crate::Result<()>;
+
+    /**
+     * Names of the saved sessions.
+     */
+    fn names(&self) -> crate::Result<Vec<String>>;
+}
+
+/**
+ * Sessions kept in memory, lost when the process exits.
+ */
+#[derive(Default)]
+pub struct MemoryStore {
+    sessions: std::sync::Mutex<std::collections::BTreeMap<String, SavedSession>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryStore {
+    fn load(&self, name: &str) -> crate::Result<Option<SavedSession>> {
+        Ok(self.sessions.lock().unwrap().get(name).cloned())
+    }
+
+    fn save(&self, name: &str, session: &SavedSession) -> crate::Result<()> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), session.clone());
+
+        Ok(())
+    }
+
+    fn remove(&self, name: &str) -> crate::Result<()> {
+        self.sessions.lock().unwrap().remove(name);
+
+        Ok(())
+    }
+
+    fn names(&self) -> crate::Result<Vec<String>> {
+        Ok(self.sessions.lock().unwrap().keys().cloned().collect())
+    }
+}
+
+/**
+ * Sessions saved in a JSON file, readable only by its owner on Unix.
+ *
+ * The file can be shared by several processes: updates are serialized by a
+ * lock on a `<path>.lock` file and the new content is written to a temporary
+ * file renamed over the old one, a crash never leaves a truncated file.
+ *
+ * Refresh tokens can only be used once: when several processes use the same
+ * session, the first one to refresh an expired token invalidates the token
+ * of the others, which then fail with an unauthorized error and have to
+ * `SessionManager::open` the session again to get the new token.
+ */
+pub struct FileStore {
+    path: std::path::PathBuf,
+}
+
+type Sessions = std::collections::BTreeMap<String, SavedSession>;
+
+impl FileStore {
+    pub fn new<P: Into<std::path::PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /**
+     * `path` with `suffix` appended to its file name.
+     */
+    fn sibling(&self, suffix: &str) -> std::path::PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(suffix);
+
+        path.into()
+    }
+
+    /**
+     * Locks the lock file, the lock is released when the returned file is
+     * dropped.
+     */
+    fn lock(&self, exclusive: bool) -> crate::Result<std::fs::File> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = Self::options().open(self.sibling(".lock"))?;
+
+        if exclusive {
+            file.lock()?;
+        } else {
+            file.lock_shared()?;
+        }
+
+        Ok(file)
+    }
+
+    fn options() -> std::fs::OpenOptions {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        options
+    }
+
+    fn read(&self) -> crate::Result<Sessions> {
+        match std::fs::read(&self.path) {
+            Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Sessions::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write(&self, sessions: &Sessions) -> crate::Result<()> {
+        let tmp = self.sibling(".tmp");
+        let mut file = Self::options().open(&tmp)?;
+
+        serde_json::to_writer_pretty(&mut file, sessions)?;
+        file.sync_all()?;
+        std::fs::rename(tmp, &self.path)?;
+
+        Ok(())
+    }
+
+    fn update<F: FnOnce(&mut Sessions)>(&self, f: F) -> crate::Result<()> {
+        let _lock = self.lock(true)?;
+        let mut sessions = self.read()?;
+
+        f(&mut sessions);
+
+        self.write(&sessions)
+    }
+}
+
+impl TokenStore for FileStore {
+    fn load(&self, name: &str) -> crate::Result<Option<SavedSession>> {
+        let _lock = self.lock(false)?;
+
+        Ok(self.read()?.remove(name))
+    }
+
+    fn save(&self, name: &str, session: &SavedSession) -> crate::Result<()> {
+        self.update(|sessions| {
+            sessions.insert(name.to_string(), session.clone());
+        })
+    }
+
+    fn remove(&self, name: &str) -> crate::Result<()> {
+        self.update(|sessions| {
+            sessions.remove(name);
+        })
+    }
+
+    fn names(&self) -> crate::Result<Vec<String>> {
+        let _lock = self.lock(false)?;
+
+        Ok(self.read()?.into_keys().collect())
+    }
+}
+
+/**
+ * Named sessions, possibly on different instances, saved in a
+ * `TokenStore`.
+ *
+ * Tokens refreshed by an `Api` returned by `open` or given to `login` are
+ * saved again automatically.
+ */
+pub struct SessionManager {
+    store: std::sync::Arc<dyn TokenStore>,
+}
+
+impl SessionManager {
+    pub fn new<S: TokenStore + 'static>(store: S) -> Self {
+        Self {
+            store: std::sync::Arc::new(store),
+        }
+    }
+
+    /**
+     * Authenticates on `api` and saves the session as `name`.
+     */
+    pub async fn login(
+        &self,
+        name: &str,
+        api: &crate::Api,
+        username: &str,
+        password: &str,
+    ) -> crate::Result<crate::data::Token> {
+        let token = api.auth(username, password).await?;
+        self.attach(name, api, username).await?;
+
+        Ok(token)
+    }
+
+    /**
+     * Saves the session of an already authenticated `api` as `name`, for
+     * other authentication methods like `Api::auth_with_otp`.
+     */
+    pub async fn attach(&self, name: &str, api: &crate::Api, username: &str) -> crate::Result<()> {
+        api.conf
+            .session
+            .persist(&api.conf, self.store.clone(), name, username)
+            .await
+    }
+
+    /**
+     * Creates an API client using the session `name`, `None` if there is no
+     * such session.
+     */
+    pub async fn open(&self, name: &str) -> crate::Result<Option<crate::Api>> {
+        let Some(saved) = self.store.load(name)? else {
+            return Ok(None);
+        };
+
+        let api = crate::Api::new(&saved.instance);
+
+        self.restore_into(name, &api, saved).await?;
+
+        Ok(Some(api))
+    }
+
+    /**
+     * Like `open`, with an API client built by the caller, for example with
+     * `Api::builder`.
+     *
+     * Returns `false` if there is no such session.
+     */
+    pub async fn restore(&self, name: &str, api: &crate::Api) -> crate::Result<bool> {
+        let Some(saved) = self.store.load(name)? else {
+            return Ok(false);
+        };
+
+        self.restore_into(name, api, saved).await?;
+
+        Ok(true)
+    }
+
+    async fn restore_into(
+        &self,
+        name: &str,
+        api: &crate::Api,
+        saved: SavedSession,
+    ) -> crate::Result<()> {
+        let username = saved.username.clone();
+
+        api.conf.session.restore(saved).await;
+        self.attach(name, api, &username).await
+    }
+
+    /**
+     * Revokes the token of the session `name` and removes the session.
+     *
+     * The session is removed even if the token was already invalid.
+     */
+    pub async fn logout(&self, name: &str) -> crate::Result<()> {
+        if let Some(api) = self.open(name).await? {
+            let result = match api.token().await {
+                Ok(Some(token)) => api.logout(&token).await,
+                Ok(None) => Ok(()),
+                Err(err) => Err(err),
+            };
+
+            api.conf.session.clear().await;
+
+            match result {
+                Err(err) if !err.is_unauthorized() && !err.is_bad_request() => return Err(err),
+                _ => (),
+            }
+        }
+
+        self.store.remove(name)
+    }
+
+    /**
+     * Names of the saved sessions.
+     */
+    pub fn names(&self) -> crate::Result<Vec<String>> {
+        self.store.names()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn saved() -> super::SavedSession {
+        super::SavedSession {
+            instance: "http://localhost:9000".to_string(),
+            username: "root".to_string(),
+            client_id: "client".to_string(),
+            client_secret: "secret".to_string(),
+            token: serde_json::from_value(crate::testing::fixtures::token()).unwrap(),
+            expires_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn file_store() {
+        use super::TokenStore;
+
+        let path =
+            std::env::temp_dir().join(format!("peertube-sessions-{}.json", std::process::id()));
+        let store = super::FileStore::new(&path);
+
+        assert!(store.load("admin").unwrap().is_none());
+
+        store.save("admin", &saved()).unwrap();
+        store.save("other", &saved()).unwrap();
+        store.remove("other").unwrap();
+
+        let store = super::FileStore::new(&path);
+
+        assert_eq!(store.names().unwrap(), ["admin"]);
+        assert_eq!(
+            store.load("admin").unwrap().unwrap().token.refresh_token,
+            saved().token.refresh_token
+        );
+        assert!(!store.sibling(".tmp").exists());
+
+        std::fs::remove_file(store.sibling(".lock")).unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn manager() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let sessions = super::SessionManager::new(super::MemoryStore::new());
+
+        sessions
+            .login("admin", &server.api(), "root", "password")
+            .await
+            .unwrap();
+        assert_eq!(sessions.names().unwrap(), ["admin"]);
+
+        let api = sessions.open("admin").await.unwrap().unwrap();
+        let token = api.token().await.unwrap().unwrap();
+        assert!(api.me.info(&token).await.is_ok());
+
+        sessions.logout("admin").await.unwrap();
+        assert!(sessions.names().unwrap().is_empty());
+        assert!(sessions.open("admin").await.unwrap().is_none());
+
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path, "/api/v1/users/revoke-token");
+    }
+
+    #[tokio::test]
+    async fn other_account() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let store = std::sync::Arc::new(super::MemoryStore::new());
+        let sessions = super::SessionManager {
+            store: store.clone(),
+        };
+        let api = server.api();
+
+        sessions
+            .login("admin", &api, "root", "password")
+            .await
+            .unwrap();
+
+        server.mock_fn("POST", "/api/v1/users/token", |request| {
+            let mut token = crate::testing::fixtures::token();
+
+            if request.text().contains("grant_type=refresh_token") {
+                token["access_token"] = "bob-refreshed".into();
+            } else {
+                token["expires_in"] = 0.into();
+            }
+
+            crate::testing::Response::json(token)
+        });
+
+        let token = api.auth("bob", "password").await.unwrap();
+        api.me.info(&token).await.unwrap();
+
+        let saved = super::TokenStore::load(&*store, "admin").unwrap().unwrap();
+
+        assert_ne!(saved.token.access_token, "bob-refreshed");
+    }
+}
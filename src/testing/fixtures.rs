@@ -0,0 +1,1491 @@
+/*!
+ * Canned responses of the mock server, they can be modified before being
+ * mounted with `MockServer::mock`.
+ */
+
+use super::{MockServer, Request, Response};
+use serde_json::{Value, json};
+
+pub const DATE: &str = "2026-01-01T00:00:00.000Z";
+pub const UUID: &str = "9c9de5e8-0a1e-484a-b099-e80766180a6d";
+pub const ACCESS_TOKEN: &str = "90286a0bdf0f7315d9d3fe8dabf9e1d2be9c97d0";
+pub const REFRESH_TOKEN: &str = "2e0d675df9fc96d2e4ec8a3ebbbf45eca9137bb7";
+
+/**
+ * Paged list of `data`.
+ */
+pub fn pager(data: Vec<Value>) -> Value {
+    json!({
+        "total": data.len(),
+        "data": data,
+    })
+}
+
+pub fn oauth_client() -> Value {
+    json!({
+        "client_id": "v1ikx5hnfop4mdpnci8nsqh93c45rldf",
+        "client_secret": "AjWiOapPltI6EnsWQwlFarRtLh4u8tDt",
+    })
+}
+
+pub fn token() -> Value {
+    json!({
+        "access_token": ACCESS_TOKEN,
+        "token_type": "Bearer",
+        "expires_in": 86400,
+        "refresh_token": REFRESH_TOKEN,
+    })
+}
+
+pub fn avatar() -> Value {
+    json!({
+        "path": "/lazy-static/avatars/avatar.png",
+        "createdAt": DATE,
+        "updatedAt": DATE,
+    })
+}
+
+pub fn account() -> Value {
+    json!({
+        "url": "http://localhost/accounts/root",
+        "name": "root",
+        "host": "localhost",
+        "avatar": avatar(),
+        "id": 1,
+        "hostRedundancyAllowed": null,
+        "followingCount": 0,
+        "followersCount": 0,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "displayName": "root",
+        "description": null,
+    })
+}
+
+fn second_account() -> Value {
+    let mut account = account();
+    account["id"] = 2.into();
+    account["name"] = "user".into();
+    account["displayName"] = "user".into();
+    account["url"] = "http://localhost/accounts/user".into();
+
+    account
+}
+
+pub fn account_summary() -> Value {
+    json!({
+        "avatar": avatar(),
+        "displayName": "root",
+        "host": "localhost",
+        "id": 1,
+        "name": "root",
+        "url": "http://localhost/accounts/root",
+    })
+}
+
+pub fn channel_summary() -> Value {
+    json!({
+        "avatar": null,
+        "displayName": "Main root channel",
+        "host": "localhost",
+        "id": 1,
+        "name": "root_channel",
+        "url": "http://localhost/video-channels/root_channel",
+    })
+}
+
+pub fn channel() -> Value {
+    json!({
+        "displayName": "Main root channel",
+        "description": null,
+        "support": null,
+        "id": "1",
+        "isLocal": true,
+        "updatedAt": DATE,
+        "ownerAccount": {
+            "id": 1,
+        },
+    })
+}
+
+pub fn video() -> Value {
+    json!({
+        "account": account_summary(),
+        "blacklisted": false,
+        "blacklistedReason": null,
+        "category": {
+            "id": 15,
+            "label": "Science & Technology",
+        },
+        "channel": channel_summary(),
+        "createdAt": DATE,
+        "truncatedDescription": null,
+        "description": null,
+        "dislikes": 0,
+        "duration": 5,
+        "embedPath": format!("/videos/embed/{UUID}"),
+        "id": 1,
+        "isLike": null,
+        "isLocal": true,
+        "language": {
+            "id": "en",
+            "label": "English",
+        },
+        "licence": {
+            "id": 1,
+            "label": "Attribution",
+        },
+        "likes": 0,
+        "name": "video",
+        "nsfw": false,
+        "originallyPublishedAt": null,
+        "previewPath": format!("/lazy-static/previews/{UUID}.jpg"),
+        "privacy": {
+            "id": 1,
+            "label": "Public",
+        },
+        "publishedAt": DATE,
+        "scheduledUpdate": null,
+        "state": {
+            "id": 1,
+            "label": "Published",
+        },
+        "thumbnailPath": format!("/static/thumbnails/{UUID}.jpg"),
+        "updateAt": DATE,
+        "userHistory": null,
+        "uuid": UUID,
+        "views": 0,
+        "waitTranscoding": false,
+    })
+}
+
+pub fn video_file(resolution: u32) -> Value {
+    json!({
+        "id": resolution,
+        "resolution": {
+            "id": resolution,
+            "label": format!("{resolution}p"),
+        },
+        "size": 1024,
+        "fps": 30,
+        "magnetUri": format!("magnet:?xs=http://localhost/lazy-static/torrents/{UUID}-{resolution}.torrent"),
+        "torrentUrl": format!("http://localhost/lazy-static/torrents/{UUID}-{resolution}.torrent"),
+        "torrentDownloadUrl": format!("http://localhost/download/torrents/{UUID}-{resolution}.torrent"),
+        "fileUrl": format!("http://localhost/static/web-videos/{UUID}-{resolution}.mp4"),
+        "fileDownloadUrl": format!("http://localhost/download/web-videos/{UUID}-{resolution}.mp4"),
+        "metadataUrl": format!("http://localhost/api/v1/videos/1/metadata/{resolution}"),
+    })
+}
+
+pub fn video_details() -> Value {
+    let mut video = video();
+    let details = json!({
+        "support": null,
+        "tags": ["tag"],
+        "commentsEnabled": true,
+        "downloadEnabled": true,
+        "trackerUrls": ["ws://localhost/tracker/socket"],
+        "files": [video_file(720)],
+        "streamingPlaylists": [{
+            "id": 1,
+            "type": 1,
+            "playlistUrl": format!("http://localhost/static/streaming-playlists/hls/{UUID}/master.m3u8"),
+            "segmentsSha256Url": format!("http://localhost/static/streaming-playlists/hls/{UUID}/segments-sha256.json"),
+            "files": [video_file(480), video_file(720)],
+            "redundancies": [],
+        }],
+    });
+
+    if let (Value::Object(video), Value::Object(details)) = (&mut video, details) {
+        video.extend(details);
+    }
+
+    video
+}
+
+pub fn video_info() -> Value {
+    json!({
+        "id": 1,
+        "uuid": UUID,
+        "name": "video",
+        "channel": channel_summary(),
+    })
+}
+
+pub fn user() -> Value {
+    #[cfg(not(feature = "v5"))]
+    let role = json!(0);
+    #[cfg(feature = "v5")]
+    let role = json!({
+        "id": 0,
+        "label": "Administrator",
+    });
+
+    json!({
+        "id": 1,
+        "username": "root",
+        "email": "admin@localhost",
+        "pluginAuth": null,
+        "theme": "instance-default",
+        "emailVerified": true,
+        "nsfwPolicy": "display",
+        "webTorrentEnabled": true,
+        "autoPlayVideo": true,
+        "role": role,
+        "roleLabel": "Administrator",
+        "videoQuota": -1,
+        "videoQuotaDaily": -1,
+        "noInstanceConfigWarningModal": true,
+        "noWelcomeModal": true,
+        "blocked": false,
+        "blockedReason": null,
+        "createdAt": DATE,
+        "account": account(),
+        "videoChannels": [channel_summary()],
+    })
+}
+
+pub fn two_factor_request() -> Value {
+    json!({
+        "requestToken": "8b1f5b8e1c0a4f2e9d3c7a6b5e4d3c2b",
+        "secret": "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ",
+        "uri": "otpauth://totp/PeerTube:root?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=PeerTube",
+    })
+}
+
+pub fn registration() -> Value {
+    json!({
+        "id": 1,
+        "state": {
+            "id": 1,
+            "label": "Pending",
+        },
+        "registrationReason": "I want to upload videos",
+        "moderationResponse": null,
+        "username": "test",
+        "email": "test@example.org",
+        "emailVerified": null,
+        "accountDisplayName": "test",
+        "channelHandle": null,
+        "channelDisplayName": null,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "user": null,
+    })
+}
+
+pub fn new_user() -> Value {
+    json!({
+        "user": {
+            "id": 2,
+            "account": {
+                "id": 2,
+            },
+        },
+    })
+}
+
+pub fn import() -> Value {
+    json!({
+        "id": 1,
+        "targetUrl": "https://example.com/video.mp4",
+        "magnetUri": null,
+        "torrentName": null,
+        "state": {
+            "id": 2,
+            "label": "Success",
+        },
+        "error": null,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "video": video(),
+    })
+}
+
+pub fn quota() -> Value {
+    json!({
+        "videoQuotaUsed": 0,
+        "videoQuotaUsedDaily": 0,
+    })
+}
+
+pub fn rating() -> Value {
+    json!({
+        "videoId": 1,
+        "rating": "like",
+    })
+}
+
+pub fn abuse() -> Value {
+    json!({
+        "id": 1,
+        "reason": "spam",
+        "predefinedReasons": [],
+        "reporterAccount": account(),
+        "state": 1,
+        "moderationComment": "",
+        "video": video_info(),
+        "createdAt": DATE,
+    })
+}
+
+pub fn abuse_message() -> Value {
+    json!({
+        "id": 1,
+        "message": "message",
+        "byModerator": true,
+        "createdAt": DATE,
+        "account": account_summary(),
+    })
+}
+
+pub fn subscription() -> Value {
+    json!({
+        "avatar": null,
+        "createdAt": DATE,
+        "description": null,
+        "displayName": "Main root channel",
+        "followersCount": 1,
+        "followingCount": 0,
+        "host": "localhost",
+        "hostRedundancyAllowed": null,
+        "id": 1,
+        "isLocal": true,
+        "name": "root_channel",
+        "ownerAccount": account(),
+        "support": null,
+        "updatedAt": DATE,
+        "url": "http://localhost/video-channels/root_channel",
+    })
+}
+
+pub fn notification() -> Value {
+    json!({
+        "account": null,
+        "comment": null,
+        "createdAt": DATE,
+        "id": 1,
+        "read": false,
+        "type": 6,
+        "updatedAt": DATE,
+        "video": video_info(),
+    })
+}
+
+pub fn comment() -> Value {
+    json!({
+        "account": account(),
+        "createdAt": DATE,
+        "id": 1,
+        "inReplyToCommentId": null,
+        "text": "comment",
+        "threadId": 1,
+        "totalReplies": 0,
+        "totalRepliesFromVideoAuthor": 0,
+        "isDeleted": false,
+        "deletedAt": null,
+        "updatedAt": DATE,
+        "url": "http://localhost/videos/watch/1/comments/1",
+        "videoId": 1,
+    })
+}
+
+pub fn thread() -> Value {
+    json!({
+        "comment": comment(),
+        "children": [],
+    })
+}
+
+pub fn playlist() -> Value {
+    json!({
+        "createdAt": DATE,
+        "description": null,
+        "displayName": "playlist",
+        "id": 1,
+        "isLocal": true,
+        "ownerAccount": account_summary(),
+        "privacy": {
+            "id": 1,
+            "label": "Public",
+        },
+        "thumbnailPath": null,
+        "type": {
+            "id": 1,
+            "label": "Regular",
+        },
+        "updatedAt": DATE,
+        "uuid": UUID,
+        "videoChannel": channel_summary(),
+        "videosLength": 0,
+    })
+}
+
+pub fn live() -> Value {
+    json!({
+        "rtmpUrl": "rtmp://localhost:1935/live",
+        "rtmpsUrl": null,
+        "streamKey": "4e5a6a4d-b3c4-4a5f-a4b3-a5e8e6d0e7d5",
+        "saveReplay": true,
+        "permanentLive": false,
+        "latencyMode": 1,
+        "replaySettings": {
+            "privacy": 1,
+        },
+    })
+}
+
+pub fn video_password() -> Value {
+    json!({
+        "id": 1,
+        "password": "secret",
+        "videoId": 1,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+    })
+}
+
+pub fn chapter() -> Value {
+    json!({
+        "timecode": 90,
+        "title": "Demo",
+    })
+}
+
+pub fn video_source() -> Value {
+    json!({
+        "filename": "video.mp4",
+        "inputFilename": "video.mp4",
+        "fileDownloadUrl": format!("http://localhost:9000/download/original-video-files/{UUID}.mp4"),
+        "resolution": {
+            "id": 720,
+            "label": "720p",
+        },
+        "size": 1024,
+        "fps": 30,
+        "width": 1280,
+        "height": 720,
+        "createdAt": DATE,
+    })
+}
+
+pub fn live_session() -> Value {
+    json!({
+        "id": 1,
+        "startDate": DATE,
+        "endDate": DATE,
+        "error": null,
+        "saveReplay": true,
+        "endingProcessed": true,
+        "replayVideo": {
+            "id": 2,
+            "uuid": "6b1bd2a5-3b1c-4b5e-9a1f-2c3d4e5f6a7b",
+            "shortUUID": "eJ4Yb8F2kVX7sT9qPmLw3n",
+        },
+    })
+}
+
+pub fn caption() -> Value {
+    json!({
+        "captionPath": format!("/lazy-static/video-captions/{UUID}-en.vtt"),
+        "language": {
+            "id": "en",
+            "label": "English",
+        },
+        "automaticallyGenerated": false,
+    })
+}
+
+pub fn ownership() -> Value {
+    json!({
+        "id": "1",
+    })
+}
+
+pub fn video_blacklist() -> Value {
+    json!({
+        "createdAt": DATE,
+        "description": "",
+        "dislikes": 0,
+        "duration": 5,
+        "id": 1,
+        "likes": 0,
+        "name": "video",
+        "nfsw": false,
+        "uuid": UUID,
+        "videoId": 1,
+        "views": 0,
+    })
+}
+
+pub fn redundancy() -> Value {
+    json!({
+        "id": 1,
+        "name": "video",
+        "url": "http://localhost/videos/watch/1",
+        "uuid": UUID,
+        "redundancies": {
+            "files": [],
+            "streamingPlaylists": [{
+                "id": 1,
+                "fileUrl": "http://localhost/static/redundancy/hls/1",
+                "strategy": "most-views",
+                "createdAt": DATE,
+                "updatedAt": DATE,
+                "expiresOn": DATE,
+                "size": 1024,
+            }],
+        },
+    })
+}
+
+pub fn blocked_account() -> Value {
+    json!({
+        "byAccount": account(),
+        "blockedAccount": account(),
+        "createdAt": DATE,
+    })
+}
+
+pub fn blocked_server() -> Value {
+    json!({
+        "byAccount": account(),
+        "createdAt": DATE,
+        "blockedServer": {
+            "host": "example.com",
+        },
+    })
+}
+
+pub fn follow() -> Value {
+    json!({
+        "id": 1,
+        "score": 20,
+        "state": "accepted",
+        "follower": account(),
+        "following": account(),
+        "createdAt": DATE,
+        "updatedAt": DATE,
+    })
+}
+
+pub fn plugin() -> Value {
+    json!({
+        "name": "hello-world",
+        "type": 1,
+        "latestVersion": "0.0.1",
+        "version": "0.0.1",
+        "enabled": true,
+        "uninstalled": false,
+        "peertubeEngine": ">=1.3.0",
+        "description": "Hello world",
+        "homepage": "https://example.com",
+        "createdAt": DATE,
+        "updatedAt": DATE,
+    })
+}
+
+pub fn available_plugin() -> Value {
+    json!({
+        "npmName": "peertube-plugin-hello-world",
+        "description": "Hello world",
+        "homepage": "https://example.com",
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "popularity": 1.,
+        "latestVersion": "0.0.1",
+        "installed": false,
+        "name": "hello-world",
+    })
+}
+
+pub fn job() -> Value {
+    json!({
+        "id": 1,
+        "state": "completed",
+        "type": "video-transcoding",
+        "data": {
+            "videoUUID": UUID,
+        },
+        "error": null,
+        "createdAt": DATE,
+        "finishedOn": DATE,
+        "processedOn": DATE,
+    })
+}
+
+pub fn runner() -> Value {
+    json!({
+        "id": 1,
+        "name": "runner",
+        "description": "",
+        "ip": "127.0.0.1",
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "lastContact": DATE,
+    })
+}
+
+pub fn new_runner() -> Value {
+    json!({
+        "id": 1,
+        "runnerToken": "ptrt-7d3b5a95-7a42-4c1b-9d4a-8c3ee9f7e1a5",
+    })
+}
+
+/**
+ * Web video transcoding job, input files are downloaded from `base_url`.
+ */
+pub fn runner_job(base_url: &str) -> Value {
+    json!({
+        "uuid": "0d5c3e09-0c7b-4a9c-8d0e-5f6a7b8c9d0e",
+        "type": "vod-web-video-transcoding",
+        "payload": {
+            "input": {
+                "videoFileUrl": format!(
+                    "{base_url}/api/v1/runners/jobs/0d5c3e09-0c7b-4a9c-8d0e-5f6a7b8c9d0e/files/videos/1/max-quality"
+                ),
+            },
+            "output": {
+                "resolution": 720,
+                "fps": 30,
+            },
+        },
+        "state": {
+            "id": 2,
+            "label": "Processing",
+        },
+        "progress": null,
+        "priority": 100,
+        "failures": 0,
+        "error": null,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+        "startedAt": DATE,
+        "finishedAt": null,
+        "jobToken": "ptrjt-2d5f0b1e-6b43-4a7e-8f4c-9c1d2e3f4a5b",
+    })
+}
+
+pub fn runner_registration_token() -> Value {
+    json!({
+        "id": 1,
+        "registrationToken": "ptrrt-4a3b2c1d-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+        "registeredRunnersCount": 1,
+        "createdAt": DATE,
+        "updatedAt": DATE,
+    })
+}
+
+fn size(max: u64) -> Value {
+    json!({
+        "size": {
+            "max": max,
+        },
+        "extensions": [".jpg", ".png", ".mp4", ".vtt", ".srt"],
+    })
+}
+
+fn instance() -> Value {
+    json!({
+        "name": "PeerTube",
+        "shortDescription": "",
+        "description": "",
+        "terms": "",
+        "codeOfConduct": "",
+        "hardwareInformation": "",
+        "creationReason": "",
+        "moderationInformation": "",
+        "administrator": "",
+        "maintenanceLifetime": "",
+        "businessModel": "",
+        "languages": [],
+        "categories": [],
+    })
+}
+
+fn algorithms() -> Value {
+    json!({
+        "enabled": ["hot", "most-viewed", "most-liked"],
+        "default": "most-viewed",
+    })
+}
+
+fn broadcast_message() -> Value {
+    json!({
+        "enabled": false,
+        "message": "",
+        "level": "info",
+        "dismissable": false,
+    })
+}
+
+pub fn config() -> Value {
+    json!({
+        "instance": {
+            "name": "PeerTube",
+            "shortDescription": "",
+            "isNSFW": false,
+            "defaultNSFWPolicy": "display",
+            "defaultClientRoute": "/videos/trending",
+            "customizations": {
+                "javascript": "",
+                "css": "",
+            },
+        },
+        "search": {
+            "remoteUri": {
+                "users": true,
+                "anonymous": false,
+            },
+            "searchIndex": {
+                "enabled": false,
+                "url": "",
+                "disableLocalSearch": false,
+                "isDefaultSearch": false,
+            },
+        },
+        "plugin": {
+            "registered": [],
+            "registeredExternalAuths": [],
+            "registeredIdAndPassAuths": [],
+        },
+        "theme": {
+            "registered": [],
+            "default": "default",
+        },
+        "email": { "enabled": false },
+        "contactForm": { "enabled": false },
+        "serverVersion": "6.0.0",
+        "serverCommit": "",
+        "signup": {
+            "allowed": false,
+            "allowedForCurrentIP": false,
+            "requiresEmailVerification": false,
+        },
+        "transcoding": {
+            "hls": { "enabled": true },
+            "webtorrent": { "enabled": false },
+            "enabledResolutions": [720],
+            "profile": "default",
+            "availableProfiles": ["default"],
+        },
+        "live": {
+            "enabled": true,
+            "allowReplay": true,
+            "maxDuration": -1,
+            "maxInstanceLives": -1,
+            "maxUserLives": -1,
+            "transcoding": {
+                "enabled": false,
+                "enabledResolutions": [],
+                "profile": "default",
+                "availableProfiles": ["default"],
+            },
+            "rtmp": { "port": 1935 },
+        },
+        "import": {
+            "videos": {
+                "http": { "enabled": true },
+                "torrent": { "enabled": false },
+            },
+        },
+        "autoBlacklist": {
+            "videos": {
+                "ofUsers": { "enabled": false },
+            },
+        },
+        "avatar": {
+            "file": size(4_194_304),
+        },
+        "video": {
+            "image": size(4_194_304),
+            "file": size(0),
+        },
+        "videoCaption": {
+            "file": size(20_971_520),
+        },
+        "user": {
+            "videoQuota": -1,
+            "videoQuotaDaily": -1,
+        },
+        "trending": {
+            "videos": {
+                "intervalDays": 7,
+                "algorithms": algorithms(),
+            },
+        },
+        "tracker": { "enabled": true },
+        "broadcastMessage": broadcast_message(),
+    })
+}
+
+pub fn about() -> Value {
+    json!({
+        "instance": instance(),
+    })
+}
+
+pub fn custom() -> Value {
+    json!({
+        "instance": instance(),
+        "theme": { "default": "default" },
+        "services": {},
+        "cache": {
+            "previews": { "size": 500 },
+            "captions": { "size": 500 },
+            "torrents": { "size": 500 },
+        },
+        "signup": {
+            "enabled": false,
+            "limit": 10,
+            "requiresEmailVerification": false,
+        },
+        "admin": { "email": "admin@localhost" },
+        "contactForm": { "enabled": false },
+        "user": {
+            "videoQuota": -1,
+            "videoQuotaDaily": -1,
+        },
+        "transcoding": {
+            "enabled": true,
+            "allowAdditionalExtensions": false,
+            "allowAudioFiles": false,
+            "threads": 1,
+            "concurrency": 1,
+            "profile": "default",
+            "resolutions": { "720p": true },
+            "webtorrent": { "enabled": false },
+            "hls": { "enabled": true },
+        },
+        "live": {
+            "enabled": true,
+            "allowReplay": true,
+            "maxDuration": -1,
+            "maxInstanceLives": -1,
+            "maxUserLives": -1,
+            "transcoding": {
+                "enabled": false,
+                "threads": 1,
+                "profile": "default",
+                "resolutions": {},
+            },
+        },
+        "import": {
+            "videos": {
+                "concurrency": 1,
+                "http": { "enabled": true },
+                "torrent": { "enabled": false },
+            },
+        },
+        "trending": {
+            "videos": {
+                "algorithms": algorithms(),
+            },
+        },
+        "autoBlacklist": {
+            "videos": {
+                "ofUsers": { "enabled": false },
+            },
+        },
+        "followers": {
+            "instance": {
+                "enabled": true,
+                "manualApproval": false,
+            },
+        },
+        "followings": {
+            "instance": {
+                "autoFollowBack": { "enabled": false },
+                "autoFollowIndex": {
+                    "enabled": false,
+                    "indexUrl": "",
+                },
+            },
+        },
+        "broadcastMessage": broadcast_message(),
+        "search": {
+            "remoteUri": {
+                "users": true,
+                "anonymous": false,
+            },
+            "searchIndex": {
+                "enabled": false,
+                "url": "",
+                "disableLocalSearch": false,
+                "isDefaultSearch": false,
+            },
+        },
+    })
+}
+
+fn new_content(kind: &str, id: u32) -> Response {
+    Response::json(json!({
+        kind: {
+            "id": id,
+            "uuid": UUID,
+        },
+    }))
+}
+
+/**
+ * Answers the chunks of a resumable upload, the upload is complete when the
+ * last byte of the file is received.
+ */
+fn resumable(request: &Request, done: Response) -> Response {
+    let range = request
+        .header("content-range")
+        .and_then(|x| x.strip_prefix("bytes "))
+        .and_then(|x| x.split_once('/'));
+
+    let Some((range, size)) = range else {
+        return Response::empty().status(400);
+    };
+
+    let received = range
+        .split_once('-')
+        .and_then(|(_, end)| end.parse::<u64>().ok())
+        .map(|x| x + 1);
+
+    match received {
+        Some(received) if size.parse() == Ok(received) => done,
+        Some(received) => Response::empty()
+            .status(308)
+            .header("range", &format!("bytes=0-{}", received - 1)),
+        None => Response::empty().status(308),
+    }
+}
+
+fn base_url(request: &Request) -> String {
+    format!("http://{}", request.header("host").unwrap_or("localhost"))
+}
+
+/**
+ * Mounts the default routes.
+ */
+pub(super) fn mount(server: &MockServer) {
+    let ok = Response::empty;
+
+    let routes = [
+        // Auth
+        (
+            "GET",
+            "/oauth-clients/local",
+            Response::json(oauth_client()),
+        ),
+        ("POST", "/users/token", Response::json(token())),
+        ("POST", "/users/revoke-token", ok()),
+        // Abuses
+        ("GET", "/abuses", Response::json(pager(vec![abuse()]))),
+        ("POST", "/abuses", new_content("abuse", 1)),
+        ("PUT", "/abuses/*", ok()),
+        ("DELETE", "/abuses/*", ok()),
+        (
+            "GET",
+            "/abuses/*/messages",
+            Response::json(pager(vec![abuse_message()])),
+        ),
+        ("POST", "/abuses/*/messages", new_content("abuseMessage", 1)),
+        ("DELETE", "/abuses/*/messages/*", ok()),
+        // Accounts
+        (
+            "GET",
+            "/accounts",
+            Response::json(pager(vec![account(), second_account()])),
+        ),
+        ("GET", "/accounts/*", Response::json(account())),
+        (
+            "GET",
+            "/accounts/*/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        (
+            "GET",
+            "/accounts/*/video-channels",
+            Response::json(pager(vec![channel()])),
+        ),
+        (
+            "GET",
+            "/accounts/*/ratings",
+            Response::json(pager(vec![channel()])),
+        ),
+        // Channels
+        (
+            "GET",
+            "/video-channels",
+            Response::json(pager(vec![channel()])),
+        ),
+        ("POST", "/video-channels", new_content("videoChannel", 1)),
+        ("GET", "/video-channels/*", Response::json(channel())),
+        ("PUT", "/video-channels/*", ok()),
+        ("DELETE", "/video-channels/*", ok()),
+        (
+            "GET",
+            "/video-channels/*/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        ("POST", "/video-channels/*/avatar/pick", ok()),
+        ("DELETE", "/video-channels/*/avatar", ok()),
+        // Config
+        ("GET", "/config", Response::json(config())),
+        ("GET", "/config/about", Response::json(about())),
+        ("GET", "/config/custom", Response::json(custom())),
+        ("PUT", "/config/custom", Response::json(custom())),
+        ("DELETE", "/config/custom", ok()),
+        // Jobs
+        ("GET", "/jobs", Response::json(pager(vec![job()]))),
+        // Me
+        ("GET", "/users/me", Response::json(user())),
+        ("PUT", "/users/me", ok()),
+        (
+            "GET",
+            "/users/me/videos/imports",
+            Response::json(pager(vec![import()])),
+        ),
+        ("GET", "/users/me/video-quota-used", Response::json(quota())),
+        ("GET", "/users/me/videos/*/rating", Response::json(rating())),
+        (
+            "GET",
+            "/users/me/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        ("POST", "/users/me/avatar/pick", Response::json(avatar())),
+        ("POST", "/users/me/avatar", ok()),
+        ("DELETE", "/users/me/avatar", ok()),
+        (
+            "GET",
+            "/users/me/abuses",
+            Response::json(pager(vec![abuse()])),
+        ),
+        (
+            "GET",
+            "/users/me/history/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        ("POST", "/users/me/history/videos/remove", ok()),
+        (
+            "GET",
+            "/users/me/notifications",
+            Response::json(pager(vec![notification()])),
+        ),
+        ("POST", "/users/me/notifications/read", ok()),
+        ("POST", "/users/me/notifications/read-all", ok()),
+        ("PUT", "/users/me/notification-settings", ok()),
+        (
+            "GET",
+            "/users/me/subscriptions",
+            Response::json(pager(vec![subscription()])),
+        ),
+        ("POST", "/users/me/subscriptions", ok()),
+        (
+            "GET",
+            "/users/me/subscriptions/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        (
+            "GET",
+            "/users/me/subscriptions/*",
+            Response::json(subscription()),
+        ),
+        ("DELETE", "/users/me/subscriptions/*", ok()),
+        // Playlists
+        (
+            "GET",
+            "/video-playlists/privacies",
+            Response::json(json!({ "1": "Public", "2": "Unlisted", "3": "Private" })),
+        ),
+        (
+            "GET",
+            "/video-playlists",
+            Response::json(pager(vec![playlist()])),
+        ),
+        ("POST", "/video-playlists", new_content("videoPlaylist", 1)),
+        ("GET", "/video-playlists/*", Response::json(playlist())),
+        ("PUT", "/video-playlists/*", ok()),
+        ("DELETE", "/video-playlists/*", ok()),
+        (
+            "GET",
+            "/video-playlists/*/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        (
+            "POST",
+            "/video-playlists/*/videos",
+            new_content("videoPlaylistElement", 1),
+        ),
+        ("POST", "/video-playlists/*/videos/reorder", ok()),
+        ("PUT", "/video-playlists/*/videos/*", ok()),
+        ("DELETE", "/video-playlists/*/videos/*", ok()),
+        (
+            "GET",
+            "/users/me/video-playlists/videos-exist",
+            Response::json(json!({})),
+        ),
+        // Plugins
+        ("GET", "/plugins", Response::json(pager(vec![plugin()]))),
+        (
+            "GET",
+            "/plugins/available",
+            Response::json(pager(vec![available_plugin()])),
+        ),
+        ("POST", "/plugins/install", ok()),
+        ("POST", "/plugins/update", ok()),
+        ("POST", "/plugins/uninstall", ok()),
+        ("GET", "/plugins/*", Response::json(plugin())),
+        ("PUT", "/plugins/*/settings", ok()),
+        (
+            "GET",
+            "/plugins/*/public-settings",
+            Response::json(json!({ "publicSettings": {} })),
+        ),
+        (
+            "GET",
+            "/plugins/*/registered-settings",
+            Response::json(json!({ "registeredSettings": [] })),
+        ),
+        // Runners
+        ("GET", "/runners", Response::json(pager(vec![runner()]))),
+        ("DELETE", "/runners/*", ok()),
+        ("POST", "/runners/register", Response::json(new_runner())),
+        ("POST", "/runners/unregister", ok()),
+        (
+            "GET",
+            "/runners/registration-tokens",
+            Response::json(pager(vec![runner_registration_token()])),
+        ),
+        ("POST", "/runners/registration-tokens/generate", ok()),
+        ("DELETE", "/runners/registration-tokens/*", ok()),
+        ("POST", "/runners/jobs/*/cancel", ok()),
+        ("DELETE", "/runners/jobs/*", ok()),
+        ("POST", "/runners/jobs/*/abort", ok()),
+        ("POST", "/runners/jobs/*/update", ok()),
+        ("POST", "/runners/jobs/*/error", ok()),
+        ("POST", "/runners/jobs/*/success", ok()),
+        (
+            "POST",
+            "/runners/jobs/*/files/videos/*/max-quality",
+            Response::bytes(&include_bytes!("../../fixtures/video.mp4")[..]),
+        ),
+        // Search
+        (
+            "GET",
+            "/search/videos",
+            Response::json(pager(vec![video()])),
+        ),
+        (
+            "GET",
+            "/search/video-channels",
+            Response::json(pager(vec![channel()])),
+        ),
+        // Server
+        (
+            "GET",
+            "/server/blocklist/accounts",
+            Response::json(pager(vec![blocked_account()])),
+        ),
+        ("POST", "/server/blocklist/accounts", ok()),
+        ("DELETE", "/server/blocklist/accounts/*", ok()),
+        (
+            "GET",
+            "/server/blocklist/servers",
+            Response::json(pager(vec![blocked_server()])),
+        ),
+        ("POST", "/server/blocklist/servers", ok()),
+        ("DELETE", "/server/blocklist/servers/*", ok()),
+        (
+            "GET",
+            "/server/following",
+            Response::json(pager(vec![follow()])),
+        ),
+        ("POST", "/server/following", ok()),
+        ("DELETE", "/server/following/*", ok()),
+        (
+            "GET",
+            "/server/followers",
+            Response::json(pager(vec![follow()])),
+        ),
+        (
+            "GET",
+            "/server/redundancy/videos",
+            Response::json(pager(vec![redundancy()])),
+        ),
+        ("POST", "/server/redundancy/videos", ok()),
+        ("DELETE", "/server/redundancy/videos/*", ok()),
+        ("PUT", "/server/redundancy/*", ok()),
+        ("DELETE", "/server/redundancy/*", ok()),
+        // Users
+        ("GET", "/users", Response::json(pager(vec![user()]))),
+        ("POST", "/users", Response::json(new_user())),
+        ("POST", "/users/register", ok()),
+        (
+            "GET",
+            "/users/registrations",
+            Response::json(pager(vec![registration()])),
+        ),
+        (
+            "POST",
+            "/users/registrations/request",
+            Response::json(registration()),
+        ),
+        ("POST", "/users/registrations/*/accept", ok()),
+        ("POST", "/users/registrations/*/reject", ok()),
+        ("DELETE", "/users/registrations/*", ok()),
+        ("POST", "/users/registrations/ask-send-verify-email", ok()),
+        ("POST", "/users/registrations/*/verify-email", ok()),
+        ("POST", "/users/ask-send-verify-email", ok()),
+        ("POST", "/users/*/verify-email", ok()),
+        ("POST", "/users/ask-reset-password", ok()),
+        ("POST", "/users/*/reset-password", ok()),
+        (
+            "POST",
+            "/users/*/two-factor/request",
+            Response::json(json!({ "otpRequest": two_factor_request() })),
+        ),
+        ("POST", "/users/*/two-factor/confirm-request", ok()),
+        ("POST", "/users/*/two-factor/disable", ok()),
+        ("POST", "/users/*/block", ok()),
+        ("POST", "/users/*/unblock", ok()),
+        ("GET", "/users/*", Response::json(user())),
+        ("PUT", "/users/*", ok()),
+        ("DELETE", "/users/*", ok()),
+        // Videos
+        ("GET", "/videos", Response::json(pager(vec![video()]))),
+        (
+            "GET",
+            "/videos/categories",
+            Response::json(json!({ "15": "Science & Technology" })),
+        ),
+        (
+            "GET",
+            "/videos/licences",
+            Response::json(json!({ "1": "Attribution" })),
+        ),
+        (
+            "GET",
+            "/videos/languages",
+            Response::json(json!({ "en": "English" })),
+        ),
+        (
+            "GET",
+            "/videos/privacies",
+            Response::json(json!({ "1": "Public", "2": "Unlisted", "3": "Private" })),
+        ),
+        ("GET", "/videos/*", Response::json(video_details())),
+        ("PUT", "/videos/*", ok()),
+        ("DELETE", "/videos/*", ok()),
+        (
+            "GET",
+            "/videos/*/description",
+            Response::json(json!({ "description": "description" })),
+        ),
+        ("POST", "/videos/*/views", ok()),
+        ("PUT", "/videos/*/watching", ok()),
+        ("POST", "/videos/upload", new_content("video", 1)),
+        (
+            "POST",
+            "/videos/imports",
+            Response::json(json!({ "id": 1, "video": video() })),
+        ),
+        ("PUT", "/videos/*/rate", ok()),
+        ("POST", "/videos/*/blacklist", ok()),
+        ("DELETE", "/videos/*/blacklist", ok()),
+        (
+            "GET",
+            "/videos/blacklist",
+            Response::json(pager(vec![video_blacklist()])),
+        ),
+        (
+            "GET",
+            "/videos/*/captions",
+            Response::json(pager(vec![caption()])),
+        ),
+        ("PUT", "/videos/*/captions/*", ok()),
+        ("POST", "/videos/*/captions/generate", ok()),
+        (
+            "GET",
+            "/videos/*/passwords",
+            Response::json(pager(vec![video_password()])),
+        ),
+        ("PUT", "/videos/*/passwords", ok()),
+        ("DELETE", "/videos/*/passwords/*", ok()),
+        ("DELETE", "/videos/*/captions/*", ok()),
+        (
+            "GET",
+            "/videos/*/comment-threads",
+            Response::json(pager(vec![comment()])),
+        ),
+        (
+            "POST",
+            "/videos/*/comment-threads",
+            Response::json(json!({ "comment": comment() })),
+        ),
+        (
+            "GET",
+            "/videos/*/comment-threads/*",
+            Response::json(thread()),
+        ),
+        (
+            "POST",
+            "/videos/*/comments/*",
+            Response::json(json!({ "comment": comment() })),
+        ),
+        ("DELETE", "/videos/*/comments/*", ok()),
+        ("POST", "/videos/live", new_content("video", 1)),
+        ("GET", "/videos/live/*", Response::json(live())),
+        ("PUT", "/videos/live/*", ok()),
+        ("POST", "/videos/*/studio/edit", ok()),
+        (
+            "GET",
+            "/videos/live/*/sessions",
+            Response::json(pager(vec![live_session()])),
+        ),
+        (
+            "GET",
+            "/videos/*/live-session",
+            Response::json(live_session()),
+        ),
+        (
+            "GET",
+            "/videos/ownership",
+            Response::json(pager(vec![ownership()])),
+        ),
+        ("POST", "/videos/ownership/*/accept", ok()),
+        ("POST", "/videos/ownership/*/refuse", ok()),
+        ("POST", "/videos/*/give-ownership", ok()),
+        (
+            "POST",
+            "/videos/upload-resumable",
+            ok().status(201).header(
+                "location",
+                "//localhost/api/v1/videos/upload-resumable?upload_id=1",
+            ),
+        ),
+        ("DELETE", "/videos/upload-resumable", ok()),
+        ("GET", "/videos/*/source", Response::json(video_source())),
+        (
+            "GET",
+            "/videos/*/chapters",
+            Response::json(json!({ "chapters": [chapter()] })),
+        ),
+        ("PUT", "/videos/*/chapters", ok()),
+        ("DELETE", "/videos/*/source/file", ok()),
+        (
+            "POST",
+            "/videos/*/source/replace-resumable",
+            ok().status(201).header(
+                "location",
+                "//localhost/api/v1/videos/1/source/replace-resumable?upload_id=1",
+            ),
+        ),
+    ];
+
+    for (method, path, response) in routes {
+        server.mock(method, &format!("/api/v1{path}"), response);
+    }
+
+    server.mock(
+        "GET",
+        "/lazy-static/video-captions/*",
+        Response::bytes(include_str!("../../fixtures/caption.vtt"))
+            .header("content-type", "text/vtt"),
+    );
+    server.mock_fn("GET", "/api/v1/jobs/*", |request| {
+        let mut job = job();
+
+        if let Some(r#type) = request.param("jobType") {
+            job["type"] = r#type.into();
+        }
+
+        Response::json(pager(vec![job]))
+    });
+    server.mock_fn("PUT", "/api/v1/videos/upload-resumable", |request| {
+        resumable(request, new_content("video", 1))
+    });
+    server.mock_fn(
+        "PUT",
+        "/api/v1/videos/*/source/replace-resumable",
+        |request| resumable(request, Response::empty()),
+    );
+    server.mock_fn("POST", "/api/v1/runners/jobs/request", |request| {
+        Response::json(json!({ "availableJobs": [runner_job(&base_url(request))] }))
+    });
+    server.mock_fn("GET", "/api/v1/runners/jobs", |request| {
+        let mut job = runner_job(&base_url(request));
+        job["runner"] = json!({
+            "id": 1,
+            "name": "runner",
+            "description": null,
+        });
+
+        Response::json(pager(vec![job]))
+    });
+    server.mock_fn("POST", "/api/v1/runners/jobs/*/accept", |request| {
+        Response::json(json!({ "job": runner_job(&base_url(request)) }))
+    });
+    server.mock_fn("GET", "/api/v1/users/me/subscriptions/exist", |request| {
+        let exist: serde_json::Map<_, _> = request
+            .params()
+            .into_iter()
+            .filter(|(name, _)| name == "uris")
+            .map(|(_, uri)| (uri, false.into()))
+            .collect();
+
+        Response::json(exist)
+    });
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn deserialize() {
+        use crate::data;
+        use crate::testing::fixtures::*;
+
+        fn check<T: for<'de> serde::Deserialize<'de>>(value: serde_json::Value) {
+            serde_json::from_value::<T>(value).unwrap();
+        }
+
+        check::<data::Token>(token());
+        check::<data::Account>(account());
+        check::<data::Channel>(channel());
+        check::<data::Video>(video());
+        check::<data::VideoDetails>(video_details());
+        check::<data::User>(user());
+        check::<data::Data>(new_user());
+        check::<data::NewContent>(json!({ "comment": comment() }));
+        check::<data::Import>(import());
+        check::<data::Quota>(quota());
+        check::<data::Rating>(rating());
+        check::<data::Abuse>(abuse());
+        check::<data::AbuseMessage>(abuse_message());
+        check::<data::Subscription>(subscription());
+        check::<data::Notification>(notification());
+        check::<data::Thread>(thread());
+        check::<data::Playlist>(playlist());
+        check::<data::Live>(live());
+        check::<data::LiveSession>(live_session());
+        check::<data::VideoSource>(video_source());
+        check::<data::Chapter>(chapter());
+        check::<data::VideoPassword>(video_password());
+        check::<data::Registration>(registration());
+        check::<data::TwoFactorRequest>(two_factor_request());
+        check::<data::Caption>(caption());
+        check::<data::Ownership>(ownership());
+        check::<data::VideoBlacklist>(video_blacklist());
+        check::<data::Redundancy>(redundancy());
+        check::<data::BlockedAccount>(blocked_account());
+        check::<data::BlockedServer>(blocked_server());
+        check::<data::Follow>(follow());
+        check::<data::Plugin>(plugin());
+        check::<data::AvailablePlugin>(available_plugin());
+        check::<data::Job>(job());
+        check::<data::Runner>(runner());
+        check::<data::NewRunner>(new_runner());
+        check::<data::AvailableJob>(runner_job("http://localhost"));
+        check::<data::AcceptedRunnerJob>(runner_job("http://localhost"));
+        check::<data::RunnerRegistrationToken>(runner_registration_token());
+        check::<data::Config>(config());
+        check::<data::config::About>(about());
+        check::<data::config::Custom>(custom());
+    }
+}
@@ -0,0 +1,240 @@
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+
+/**
+ * Request received by the mock server.
+ */
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    /**
+     * Path without the query string.
+     */
+    pub path: String,
+    pub query: Option<String>,
+    /**
+     * Headers, with lowercase names.
+     */
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(x, _)| x.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /**
+     * Decoded value of a query string parameter.
+     */
+    pub fn param(&self, name: &str) -> Option<String> {
+        self.params()
+            .into_iter()
+            .find(|(x, _)| x == name)
+            .map(|(_, value)| value)
+    }
+
+    /**
+     * Decoded query string parameters, a parameter can be repeated.
+     */
+    pub fn params(&self) -> Vec<(String, String)> {
+        let query = self.query.as_deref().unwrap_or_default();
+
+        reqwest::Url::parse(&format!("http://localhost/?{query}"))
+            .map(|url| url.query_pairs().into_owned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn json<T: for<'de> serde::Deserialize<'de>>(&self) -> crate::Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+}
+
+/**
+ * Response sent by the mock server.
+ */
+#[derive(Clone, Debug)]
+pub struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    /**
+     * `204 No Content` response.
+     */
+    pub fn empty() -> Self {
+        Self {
+            status: 204,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /**
+     * `200 OK` response with a JSON body.
+     */
+    pub fn json<T: serde::Serialize>(body: T) -> Self {
+        Self::bytes(serde_json::to_vec(&body).unwrap()).header("content-type", "application/json")
+    }
+
+    /**
+     * `200 OK` response with a raw body.
+     */
+    pub fn bytes<B: Into<Vec<u8>>>(body: B) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+pub(super) async fn serve(listener: tokio::net::TcpListener, state: std::sync::Arc<super::State>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let state = state.clone();
+
+        tokio::spawn(async move {
+            let mut stream = tokio::io::BufStream::new(stream);
+
+            if let Ok(Some(request)) = read(&mut stream).await {
+                let response = state.handle(request);
+                write(&mut stream, &response).await.ok();
+            }
+        });
+    }
+}
+
+async fn read<S>(stream: &mut S) -> std::io::Result<Option<Request>>
+where
+    S: tokio::io::AsyncBufRead + Unpin,
+{
+    let mut line = String::new();
+
+    if stream.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let mut headers = Vec::new();
+
+    loop {
+        line.clear();
+        stream.read_line(&mut line).await?;
+
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+
+        headers.push((name.to_ascii_lowercase(), value.trim().to_string()));
+    }
+
+    let mut request = Request {
+        method,
+        path,
+        query,
+        headers,
+        body: Vec::new(),
+    };
+
+    if request.header("transfer-encoding") == Some("chunked") {
+        request.body = read_chunked(stream).await?;
+    } else if let Some(length) = request
+        .header("content-length")
+        .and_then(|x| x.parse().ok())
+    {
+        request.body = vec![0; length];
+        stream.read_exact(&mut request.body).await?;
+    }
+
+    Ok(Some(request))
+}
+
+async fn read_chunked<S>(stream: &mut S) -> std::io::Result<Vec<u8>>
+where
+    S: tokio::io::AsyncBufRead + Unpin,
+{
+    let mut body = Vec::new();
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        stream.read_line(&mut line).await?;
+
+        let size = line.trim_end().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        if size == 0 {
+            break;
+        }
+
+        let start = body.len();
+        body.resize(start + size, 0);
+        stream.read_exact(&mut body[start..]).await?;
+
+        // CRLF after the chunk data
+        line.clear();
+        stream.read_line(&mut line).await?;
+    }
+
+    // Trailers
+    loop {
+        line.clear();
+
+        if stream.read_line(&mut line).await? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    Ok(body)
+}
+
+async fn write<S>(stream: &mut S, response: &Response) -> std::io::Result<()>
+where
+    S: tokio::io::AsyncWrite + Unpin,
+{
+    let reason = reqwest::StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|x| x.canonical_reason())
+        .unwrap_or_default();
+
+    let mut head = format!("HTTP/1.1 {} {reason}\r\n", response.status);
+
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    head.push_str(&format!(
+        "content-length: {}\r\nconnection: close\r\n\r\n",
+        response.body.len()
+    ));
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&response.body).await?;
+    stream.flush().await
+}
@@ -0,0 +1,249 @@
+/*!
+ * In-process fake PeerTube instance, to test code using `Api` without a real
+ * instance.
+ *
+ * ```no_run
+ * # async fn f() -> peertube::Result {
+ * use peertube::testing::{MockServer, Response, fixtures};
+ *
+ * let server = MockServer::start().await?;
+ *
+ * let mut video = fixtures::video_details();
+ * video["name"] = "My video".into();
+ * server.mock("GET", "/api/v1/videos/1", Response::json(video));
+ *
+ * let video = server.api().videos.get("1").await?;
+ * assert_eq!(video.name, "My video");
+ * # Ok(())
+ * # }
+ * ```
+ */
+
+pub mod fixtures;
+
+mod http;
+
+pub use http::{Request, Response};
+
+type Handler = std::sync::Arc<dyn Fn(&Request) -> Response + Send + Sync>;
+
+struct Route {
+    method: String,
+    path: String,
+    handler: Handler,
+}
+
+impl Route {
+    fn matches(&self, request: &Request) -> bool {
+        if !self.method.eq_ignore_ascii_case(&request.method) {
+            return false;
+        }
+
+        let pattern = self.path.trim_end_matches('/').split('/');
+        let path = request.path.trim_end_matches('/').split('/');
+
+        pattern.clone().count() == path.clone().count()
+            && pattern.zip(path).all(|(x, y)| x == "*" || x == y)
+    }
+
+    fn specificity(&self) -> usize {
+        self.path.split('/').filter(|x| *x != "*").count()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    routes: std::sync::Mutex<Vec<Route>>,
+    requests: std::sync::Mutex<Vec<Request>>,
+}
+
+impl State {
+    fn handle(&self, request: Request) -> Response {
+        let handler = self
+            .routes
+            .lock()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.matches(&request))
+            .max_by_key(|(n, x)| (x.specificity(), *n))
+            .map(|(_, x)| x.handler.clone());
+
+        let response = match handler {
+            Some(handler) => handler(&request),
+            None => Response::json(serde_json::json!({
+                "type": "about:blank",
+                "title": "Not Found",
+                "detail": format!("No mock for {} {}", request.method, request.path),
+                "status": 404,
+            }))
+            .status(404),
+        };
+
+        self.requests.lock().unwrap().push(request);
+
+        response
+    }
+}
+
+/**
+ * Local HTTP server answering like a PeerTube instance.
+ *
+ * Routes are matched against the method and the path, a `*` segment in the
+ * path matches any segment. The route with the fewest `*` segments wins, then
+ * the most recently added one, so `mock` overrides the default fixtures.
+ *
+ * The server stops when dropped.
+ */
+pub struct MockServer {
+    addr: std::net::SocketAddr,
+    state: std::sync::Arc<State>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+    /**
+     * Starts a server answering every endpoint of the API with the default
+     * fixtures.
+     */
+    pub async fn start() -> crate::Result<Self> {
+        let server = Self::empty().await?;
+        fixtures::mount(&server);
+
+        Ok(server)
+    }
+
+    /**
+     * Starts a server without any route, every request gets a 404 response.
+     */
+    pub async fn empty() -> crate::Result<Self> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = std::sync::Arc::new(State::default());
+
+        let task = tokio::spawn(http::serve(listener, state.clone()));
+
+        Ok(Self { addr, state, task })
+    }
+
+    /**
+     * Base URL of the server, to give to `Api::new`.
+     */
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /**
+     * Creates an API client for this server.
+     */
+    pub fn api(&self) -> crate::Api {
+        crate::Api::new(&self.url())
+    }
+
+    /**
+     * Answers requests matching `method` and `path` with `response`.
+     */
+    pub fn mock(&self, method: &str, path: &str, response: Response) {
+        self.mock_fn(method, path, move |_| response.clone());
+    }
+
+    /**
+     * Answers requests matching `method` and `path` with the response built by
+     * `handler`.
+     */
+    pub fn mock_fn<F>(&self, method: &str, path: &str, handler: F)
+    where
+        F: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        let route = Route {
+            method: method.to_string(),
+            path: path.to_string(),
+            handler: std::sync::Arc::new(handler),
+        };
+
+        self.state.routes.lock().unwrap().push(route);
+    }
+
+    /**
+     * Requests received so far, oldest first.
+     */
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn mock() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+
+        let mut video = crate::testing::fixtures::video_details();
+        video["name"] = "mocked".into();
+        server.mock(
+            "GET",
+            "/api/v1/videos/*",
+            crate::testing::Response::json(video),
+        );
+
+        let video = server.api().videos.get("1").await.unwrap();
+
+        assert_eq!(video.name, "mocked");
+        assert_eq!(server.requests()[0].path, "/api/v1/videos/1");
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let server = crate::testing::MockServer::empty().await.unwrap();
+
+        let err = server.api().videos.get("1").await.unwrap_err();
+
+        assert!(err.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn auth() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        let api = server.api();
+
+        let token = api.auth("root", "password").await.unwrap();
+        api.me.info(&token).await.unwrap();
+
+        let request = server.requests().pop().unwrap();
+
+        assert_eq!(
+            request.header("authorization"),
+            Some(format!("Bearer {}", token.access_token).as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn two_factor() {
+        let server = crate::testing::MockServer::start().await.unwrap();
+        server.mock_fn("POST", "/api/v1/users/token", |request| {
+            match request.header("x-peertube-otp") {
+                Some("123456") => crate::testing::Response::json(crate::testing::fixtures::token()),
+                _ => crate::testing::Response::json(serde_json::json!({
+                    "status": 401,
+                    "code": "missing_two_factor",
+                }))
+                .status(401),
+            }
+        });
+        let api = server.api();
+
+        let err = api.auth("root", "password").await.unwrap_err();
+        assert!(err.is_missing_two_factor());
+
+        let token = api
+            .auth_with("root", "password", || async { "123456".to_string() })
+            .await;
+        assert!(token.is_ok());
+    }
+}
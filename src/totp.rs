@@ -0,0 +1,111 @@
+/*!
+ * Time-based one-time passwords (RFC 6238), as used by PeerTube two-factor
+ * authentication: HMAC-SHA1, 6 digits and a 30 seconds period.
+ *
+ * Only available with the `testing` feature, to log in test accounts with
+ * two-factor authentication enabled.
+ *
+ * ```
+ * // Secret given by `Users::request_two_factor`
+ * let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+ *
+ * assert_eq!(peertube::totp::code_at(secret, 59).unwrap(), "287082");
+ * ```
+ */
+
+const DIGITS: u32 = 6;
+const PERIOD: u64 = 30;
+
+/**
+ * Code for the current time, `None` if `secret` isn't valid base32.
+ */
+pub fn code(secret: &str) -> Option<String> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    code_at(secret, now.as_secs())
+}
+
+/**
+ * Code for a UNIX timestamp, in seconds.
+ */
+pub fn code_at(secret: &str, timestamp: u64) -> Option<String> {
+    let key = base32(secret)?;
+    let hash = hmac_sha1(&key, &(timestamp / PERIOD).to_be_bytes());
+
+    // Dynamic truncation
+    let offset = (hash[19] & 0xf) as usize;
+    let value = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+
+    Some(format!(
+        "{:0width$}",
+        value % 10u32.pow(DIGITS),
+        width = DIGITS as usize
+    ))
+}
+
+/**
+ * Decodes RFC 4648 base32, case insensitive, padding and spaces are
+ * ignored.
+ */
+fn base32(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    let mut buffer = 0u64;
+    let mut bits = 0;
+
+    for c in input.chars().filter(|x| *x != '=' && !x.is_whitespace()) {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u8 - b'A',
+            c @ '2'..='7' => c as u8 - b'2' + 26,
+            _ => return None,
+        };
+
+        buffer = (buffer << 5) | u64::from(value);
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(output)
+}
+
+fn hmac_sha1(key: &[u8], message: &[u8]) -> [u8; 20] {
+    use hmac::Mac;
+
+    let mut mac =
+        hmac::Hmac::<sha1::Sha1>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(message);
+
+    mac.finalize().into_bytes().into()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn base32() {
+        assert_eq!(super::base32("MZXW6YTBOI======").unwrap(), b"foobar");
+        assert_eq!(super::base32("mzxw 6ytb oi").unwrap(), b"foobar");
+        assert!(super::base32("MZXW1").is_none());
+    }
+
+    #[test]
+    fn code() {
+        // RFC 6238, truncated to 6 digits
+        let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+        assert_eq!(super::code_at(secret, 59).unwrap(), "287082");
+        assert_eq!(super::code_at(secret, 1111111109).unwrap(), "081804");
+        assert_eq!(super::code_at(secret, 1234567890).unwrap(), "005924");
+        assert_eq!(super::code_at(secret, 20000000000).unwrap(), "353130");
+        assert_eq!(super::code(secret).unwrap().len(), 6);
+    }
+}